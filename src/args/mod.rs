@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::board::{BoardCoord, Rules, Shape, Variant};
+use crate::cell::Special;
+
+/// The representation of the command line arguments given to a mode, split into `--name value`
+/// options and positional arguments.
+pub struct Args {
+    /// The values of the options, keyed by name (without the leading `--`).
+    options: HashMap<String, String>,
+    /// The arguments which are not options, in order.
+    positional: Vec<String>,
+}
+
+impl Args {
+    /// Splits the arguments into options and positional arguments. Options are only recognized
+    /// before the first positional argument or a `--`, so that the arguments of a program given on
+    /// the command line are passed through untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - the arguments to split
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = HashMap::new();
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            match arg.strip_prefix("--") {
+                Some("") => break,
+                Some(name) => {
+                    let value = iter
+                        .next()
+                        .ok_or_else(|| format!("missing value for option `--{}`", name))?;
+                    options.insert(name.to_string(), value.clone());
+                }
+                None => {
+                    let positional = std::iter::once(arg).chain(iter).cloned().collect();
+                    return Ok(Self {
+                        options,
+                        positional,
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            options,
+            positional: iter.cloned().collect(),
+        })
+    }
+
+    /// Parses the value of an option, if it was given.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the option (without the leading `--`)
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        self.options
+            .get(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| format!("invalid value for option `--{}`: {}", name, value))
+            })
+            .transpose()
+    }
+
+    /// Builds the rules of a game from the `--shape`, `--variant`, `--rows`, `--cols`,
+    /// `--layers`, `--win`, `--undo`, `--walls`, `--wrap`, `--diagonal` and `--specials` options,
+    /// taking the classic rules for any option that was not given, other than the size of the
+    /// board and its number of layers, which default to those of the shape, and the winning
+    /// value, which defaults to that of the variant. Walls are given as comma-separated `row:col`
+    /// coordinates, e.g. `--walls 1:1,2:2`, wrapping and diagonal shifts as `--wrap true` and
+    /// `--diagonal true`, and the chance of spawning each special cell as comma-separated
+    /// `kind:probability` pairs, e.g. `--specials joker:0.05,bomb:0.01`.
+    pub fn rules(&self) -> Result<Rules, String> {
+        let default = Rules::default();
+        let variant = self.get::<Variant>("variant")?.unwrap_or(default.variant);
+        let shape = self.get::<Shape>("shape")?.unwrap_or(default.shape);
+        let (rows, cols) = shape.geometry().size();
+        let layers = shape.geometry().layers();
+        let walls = match self.get::<String>("walls")? {
+            Some(walls) => walls
+                .split(',')
+                .map(|wall| {
+                    wall.split_once(':')
+                        .and_then(|(row, col)| Some((row.parse().ok()?, col.parse().ok()?)))
+                        .ok_or_else(|| format!("invalid wall: {}", wall))
+                })
+                .collect::<Result<Vec<BoardCoord>, String>>()?,
+            None => default.walls,
+        };
+        let specials = match self.get::<String>("specials")? {
+            Some(specials) => specials
+                .split(',')
+                .map(|special| {
+                    special
+                        .split_once(':')
+                        .and_then(|(kind, chance)| {
+                            Some((kind.parse::<Special>().ok()?, chance.parse().ok()?))
+                        })
+                        .ok_or_else(|| format!("invalid special: {}", special))
+                })
+                .collect::<Result<_, String>>()?,
+            None => default.specials,
+        };
+        let mut rules = Rules {
+            rows: self.get("rows")?.unwrap_or(rows),
+            cols: self.get("cols")?.unwrap_or(cols),
+            layers: self.get("layers")?.unwrap_or(layers),
+            win_value: self.get("win")?.unwrap_or(variant.rule().win_value()),
+            undo_limit: self.get("undo")?.unwrap_or(default.undo_limit),
+            walls,
+            wrap: self.get("wrap")?.unwrap_or(default.wrap),
+            diagonal: self.get("diagonal")?.unwrap_or(default.diagonal),
+            shape,
+            variant,
+            specials,
+        };
+        rules
+            .validate()
+            .map_err(|_| format!("invalid rules: {}", rules))?;
+        rules.normalize();
+        Ok(rules)
+    }
+
+    /// Returns the positional arguments.
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+}
+
+/// Splits a command into words at whitespace, except where the whitespace is enclosed in single
+/// or double quotes. The quotes themselves are removed.
+///
+/// # Arguments
+///
+/// * `command` - the command to split
+pub fn split_words(command: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote: Option<char> = None;
+
+    for c in command.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => word.get_or_insert_with(String::new).push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                word.get_or_insert_with(String::new);
+            }
+            (None, c) if c.is_whitespace() => words.extend(word.take()),
+            (None, c) => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    match quote {
+        Some(q) => Err(format!("unterminated {} in command: {}", q, command)),
+        None => {
+            words.extend(word);
+            Ok(words)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,22 @@
+use super::*;
+
+/// Affirm that `Args::get` parses the value of a given option.
+#[test]
+fn present() {
+    let parsed = Args::parse(&args(&["--seed", "7"])).unwrap();
+    assert_eq!(Some(7), parsed.get::<u64>("seed").unwrap());
+}
+
+/// Affirm that `Args::get` reports nothing for an option that was not given.
+#[test]
+fn absent() {
+    let parsed = Args::parse(&args(&[])).unwrap();
+    assert_eq!(None, parsed.get::<u64>("seed").unwrap());
+}
+
+/// Affirm that `Args::get` reports an error for a value that cannot be parsed.
+#[test]
+fn invalid() {
+    let parsed = Args::parse(&args(&["--seed", "seven"])).unwrap();
+    assert!(parsed.get::<u64>("seed").is_err());
+}
@@ -0,0 +1,11 @@
+use super::*;
+
+mod get;
+mod parse;
+mod rules;
+mod split_words;
+
+/// Converts a list of string literals into owned arguments.
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|arg| arg.to_string()).collect()
+}
@@ -0,0 +1,23 @@
+use super::*;
+
+/// Affirm that options preceding the positional arguments are recognized.
+#[test]
+fn options_then_positional() {
+    let parsed = Args::parse(&args(&["--seed", "7", "bot", "--level", "3"])).unwrap();
+    assert_eq!(Some("7"), parsed.options.get("seed").map(String::as_str));
+    assert_eq!(args(&["bot", "--level", "3"]), parsed.positional());
+}
+
+/// Affirm that every argument following `--` is positional.
+#[test]
+fn separator() {
+    let parsed = Args::parse(&args(&["--", "--seed", "7"])).unwrap();
+    assert!(parsed.options.is_empty());
+    assert_eq!(args(&["--seed", "7"]), parsed.positional());
+}
+
+/// Affirm that an option lacking a value is reported as an error.
+#[test]
+fn missing_value() {
+    assert!(Args::parse(&args(&["--seed"])).is_err());
+}
@@ -0,0 +1,114 @@
+use super::*;
+
+/// Affirm that `Args::rules` takes the classic rules for any option that was not given.
+#[test]
+fn defaults() {
+    let parsed = Args::parse(&args(&["--cols", "5", "--undo", "0"])).unwrap();
+    let rules = parsed.rules().unwrap();
+    assert_eq!(
+        Rules {
+            cols: 5,
+            undo_limit: 0,
+            ..Rules::default()
+        },
+        rules
+    );
+    assert_eq!("4x5, win at 2048, 0 undos", rules.to_string());
+}
+
+/// Affirm that `Args::rules` reports an error for rules a game cannot be played with.
+#[test]
+fn invalid() {
+    let parsed = Args::parse(&args(&["--win", "100"])).unwrap();
+    assert!(parsed.rules().is_err());
+}
+
+/// Affirm that `Args::rules` reads walls as `row:col` coordinates, listing them in order.
+#[test]
+fn walls() {
+    let parsed = Args::parse(&args(&["--walls", "1:1,2:3"])).unwrap();
+    assert_eq!(vec![(1, 1), (2, 3)], parsed.rules().unwrap().walls);
+
+    // the same walls in another order make the same rules
+    let parsed = Args::parse(&args(&["--walls", "2:3,1:1"])).unwrap();
+    assert_eq!(vec![(1, 1), (2, 3)], parsed.rules().unwrap().walls);
+
+    let parsed = Args::parse(&args(&["--walls", "1:1,2"])).unwrap();
+    assert!(parsed.rules().is_err());
+}
+
+/// Affirm that `Args::rules` takes the winning value of the variant unless one is given.
+#[test]
+fn variant() {
+    let parsed = Args::parse(&args(&["--variant", "fibonacci"])).unwrap();
+    assert_eq!(2584, parsed.rules().unwrap().win_value);
+
+    let parsed = Args::parse(&args(&["--variant", "fibonacci", "--win", "2048"])).unwrap();
+    assert!(parsed.rules().is_err());
+}
+
+/// Affirm that `Args::rules` reads the chances of spawning special cells as `kind:probability`
+/// pairs, rejecting chances which sum to more than one.
+#[test]
+fn specials() {
+    let parsed = Args::parse(&args(&["--specials", "joker:0.05,bomb:0.01"])).unwrap();
+    let rules = parsed.rules().unwrap();
+    assert_eq!(Some(&0.05), rules.specials.get(&Special::Joker));
+    assert_eq!(Some(&0.01), rules.specials.get(&Special::Bomb));
+    assert_eq!(
+        "4x4, win at 2048, 1 undo, jokers at 5.0%, bombs at 1.0%",
+        rules.to_string()
+    );
+
+    let parsed = Args::parse(&args(&["--specials", "joker:0.6,doubler:0.6"])).unwrap();
+    assert!(parsed.rules().is_err());
+    let parsed = Args::parse(&args(&["--specials", "wildcard:0.1"])).unwrap();
+    assert!(parsed.rules().is_err());
+}
+
+/// Affirm that `Args::rules` reads whether the board shifts diagonally, rejecting diagonal shifts
+/// on a wrapping board.
+#[test]
+fn diagonal() {
+    let parsed = Args::parse(&args(&["--diagonal", "true"])).unwrap();
+    let rules = parsed.rules().unwrap();
+    assert!(rules.diagonal);
+    assert_eq!("4x4, win at 2048, 1 undo, diagonal", rules.to_string());
+
+    let parsed = Args::parse(&args(&["--diagonal", "true", "--wrap", "true"])).unwrap();
+    assert!(parsed.rules().is_err());
+}
+
+/// Affirm that `Args::rules` reads the shape of the board, sizing a hexagonal board to suit it
+/// unless told otherwise, and rejecting sizes and rules which do not suit it.
+#[test]
+fn shape() {
+    let parsed = Args::parse(&args(&["--shape", "hex"])).unwrap();
+    let rules = parsed.rules().unwrap();
+    assert_eq!(Shape::Hex, rules.shape);
+    assert_eq!("5x5, win at 2048, 1 undo, hex", rules.to_string());
+
+    let parsed = Args::parse(&args(&["--shape", "hex", "--rows", "7", "--cols", "7"])).unwrap();
+    assert_eq!(7, parsed.rules().unwrap().rows);
+    let parsed = Args::parse(&args(&["--shape", "hex", "--rows", "4", "--cols", "4"])).unwrap();
+    assert!(parsed.rules().is_err());
+    let parsed = Args::parse(&args(&["--shape", "hex", "--wrap", "true"])).unwrap();
+    assert!(parsed.rules().is_err());
+}
+
+/// Affirm that `Args::rules` reads the number of layers of a cube, taking four layers of four by
+/// four cells unless told otherwise, and rejecting layers on a flat board.
+#[test]
+fn layers() {
+    let parsed = Args::parse(&args(&["--shape", "cube"])).unwrap();
+    let rules = parsed.rules().unwrap();
+    assert_eq!(4, rules.layers);
+    assert_eq!("4x4x4, win at 2048, 1 undo, cube", rules.to_string());
+
+    let parsed = Args::parse(&args(&["--shape", "cube", "--layers", "3"])).unwrap();
+    assert_eq!(3, parsed.rules().unwrap().layers);
+    let parsed = Args::parse(&args(&["--layers", "2"])).unwrap();
+    assert!(parsed.rules().is_err());
+    let parsed = Args::parse(&args(&["--shape", "cube", "--diagonal", "true"])).unwrap();
+    assert!(parsed.rules().is_err());
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+/// Affirm that a command is split at runs of whitespace.
+#[test]
+fn whitespace() {
+    assert_eq!(
+        args(&["python3", "bot.py", "--depth", "3"]),
+        split_words("  python3 bot.py\t--depth  3 ").unwrap()
+    );
+}
+
+/// Affirm that quoted whitespace is kept within a word and the quotes are removed.
+#[test]
+fn quoted() {
+    assert_eq!(
+        args(&["sh", "-c", "echo \"hi\"; read x", ""]),
+        split_words("sh -c 'echo \"hi\"; read x' \"\"").unwrap()
+    );
+}
+
+/// Affirm that an unterminated quote is reported as an error.
+#[test]
+fn unterminated() {
+    assert!(split_words("sh -c 'echo").is_err());
+}
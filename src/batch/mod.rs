@@ -0,0 +1,98 @@
+use std::io::Read;
+
+use crate::board::Board;
+use crate::{parse_input, Move};
+
+/// The representation of a problem encountered while applying a stream of moves.
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The character at the (one-indexed) position in the stream is not a move.
+    InvalidInput(usize, char),
+    /// The move at the (one-indexed) position in the stream could not be performed.
+    IllegalMove(usize, Move),
+}
+
+/// Implementation of the `Display` trait for `Error`.
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidInput(pos, c) => write!(f, "move {}: invalid input `{}`", pos, c),
+            Error::IllegalMove(pos, Move::Shift(dir)) => {
+                write!(f, "move {}: cannot shift {}", pos, dir)
+            }
+            Error::IllegalMove(pos, Move::Undo) => write!(f, "move {}: nothing to undo", pos),
+        }
+    }
+}
+
+/// Applies every move in the stream to the board, continuing past any that fail. Moves are
+/// written as the characters used in interactive play (`w`, `a`, `s`, `d` and `u` for undo, as
+/// well as `q`, `e`, `z` and `c` for the corners and `i` and `o` for in and out, where the board
+/// can be shifted in their directions), in either case; whitespace is ignored.
+///
+/// # Arguments
+///
+/// * `board` - the board to apply the moves to
+/// * `moves` - the stream of moves
+///
+/// # Returns
+/// * the number of moves applied, and the problems encountered with the rest
+pub fn apply(board: &mut Board, moves: &str) -> (usize, Vec<Error>) {
+    let mut applied = 0;
+    let mut errors = Vec::new();
+
+    for (idx, c) in moves.chars().filter(|c| !c.is_whitespace()).enumerate() {
+        let pos = idx + 1;
+        match parse_input(&c.to_lowercase().to_string(), &board.rules().directions()) {
+            Ok(mov) => match board.movement(mov) {
+                Ok(_) => applied += 1,
+                Err(()) => errors.push(Error::IllegalMove(pos, mov)),
+            },
+            Err(()) => errors.push(Error::InvalidInput(pos, c)),
+        }
+    }
+    (applied, errors)
+}
+
+/// Runs the `batch` mode: applies a stream of moves to a seeded board, played by the rules given
+/// by the options (e.g. `--diagonal true`), and prints the outcome. The moves are read from
+/// `--moves`, from the file given as a positional argument, or otherwise from stdin.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let seed = args
+        .get::<u64>("seed")?
+        .ok_or("usage: batch --seed N [--moves MOVES | FILE]")?;
+
+    let moves = match (args.get::<String>("moves")?, args.positional().first()) {
+        (Some(moves), _) => moves,
+        (None, Some(path)) => {
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?
+        }
+        (None, None) => {
+            let mut moves = String::new();
+            std::io::stdin()
+                .read_to_string(&mut moves)
+                .map_err(|e| e.to_string())?;
+            moves
+        }
+    };
+
+    let mut board = Board::with_rules(args.rules()?, seed);
+    let (applied, errors) = apply(&mut board, &moves);
+
+    println!("{}\n", board);
+    println!("score: {}", board.score());
+    println!("status: {:?}", board.status());
+    println!("moves: {}/{}", applied, applied + errors.len());
+    for error in errors {
+        println!("error: {}", error);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
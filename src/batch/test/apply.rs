@@ -0,0 +1,39 @@
+use super::*;
+use crate::Direction;
+
+/// Affirm that a stream of moves reproduces the same board as applying each move by hand.
+#[test]
+fn matches_movement() {
+    let mut expected = Board::with_seed(9);
+    for mov in [
+        Move::Shift(Direction::Up),
+        Move::Shift(Direction::Left),
+        Move::Shift(Direction::Down),
+        Move::Shift(Direction::Right),
+    ] {
+        let _ = expected.movement(mov);
+    }
+
+    let mut board = Board::with_seed(9);
+    let _ = apply(&mut board, "wasd");
+    assert_eq!(expected.notation(), board.notation());
+    assert_eq!(expected.score(), board.score());
+}
+
+/// Affirm that whitespace is skipped, uppercase is accepted and invalid characters are reported
+/// at their position among the moves.
+#[test]
+fn invalid_input() {
+    let mut board = Board::with_seed(9);
+    let (_, errors) = apply(&mut board, "W a\nx");
+    assert_eq!(vec![Error::InvalidInput(3, 'x')], errors);
+}
+
+/// Affirm that moves which cannot be performed are reported and skipped.
+#[test]
+fn illegal_moves() {
+    let mut board = Board::with_seed(9);
+    let (applied, errors) = apply(&mut board, "uaa");
+    assert!(errors.contains(&Error::IllegalMove(1, Move::Undo)));
+    assert_eq!(3, applied + errors.len());
+}
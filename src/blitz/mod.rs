@@ -0,0 +1,289 @@
+use std::io::Write;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_pcg::Pcg64;
+
+use crate::board::{Board, Event};
+use crate::{parse_input, Direction, Move};
+
+/// Defines the time limit of a game in seconds, unless specified otherwise by `--time`.
+const TIME_LIMIT: u64 = 180;
+
+/// A source of the current time, measured from an arbitrary fixed point, so that a timed game can
+/// be played against a clock other than the system's.
+pub trait Clock {
+    /// Returns the time elapsed since the fixed point.
+    fn now(&self) -> Duration;
+}
+
+/// The clock of the system, measuring the time since it was created.
+pub struct SystemClock(Instant);
+
+/// Implementation of the `Default` trait for `SystemClock`.
+impl Default for SystemClock {
+    /// Starts the clock at the current time.
+    fn default() -> Self {
+        Self(Instant::now())
+    }
+}
+
+/// Implementation of the `Clock` trait for `SystemClock`.
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.0.elapsed()
+    }
+}
+
+/// The representation of what is done when the time limit of a move runs out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Expiry {
+    /// A shift in a random legal direction is played.
+    Random,
+    /// The move is forfeited: a new cell is spawned without shifting the board.
+    Forfeit,
+}
+
+/// Implementation of the `FromStr` trait for `Expiry`.
+impl std::str::FromStr for Expiry {
+    type Err = ();
+
+    /// Parses an expiry from its lowercase name, `random` or `forfeit`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "random" => Ok(Expiry::Random),
+            "forfeit" => Ok(Expiry::Forfeit),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The representation of a timed game, which ends when its time limit runs out (or when the
+/// board can no longer be shifted). Each move may also be given a time limit of its own.
+pub struct Blitz<C: Clock> {
+    /// The board of the game.
+    board: Board,
+    /// The clock the game is timed by.
+    clock: C,
+    /// The time at which the game started.
+    start: Duration,
+    /// The time at which the current move began.
+    turn: Duration,
+    /// The time limit of the game.
+    limit: Duration,
+    /// The time limit of each move, and what is done when it runs out, if moves are timed.
+    move_limit: Option<(Duration, Expiry)>,
+    /// The source of randomness for the shifts played when a move runs out of time.
+    rng: Pcg64,
+}
+
+impl<C: Clock> Blitz<C> {
+    /// Starts a timed game on the specified board.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the board of the game
+    /// * `clock` - the clock the game is timed by
+    /// * `limit` - the time limit of the game
+    /// * `move_limit` - the time limit of each move and what is done when it runs out, if any
+    /// * `seed` - the seed of the random number generator picking the shifts played when a move
+    ///   runs out of time
+    pub fn new(
+        board: Board,
+        clock: C,
+        limit: Duration,
+        move_limit: Option<(Duration, Expiry)>,
+        seed: u64,
+    ) -> Self {
+        let start = clock.now();
+        Self {
+            board,
+            clock,
+            start,
+            turn: start,
+            limit,
+            move_limit,
+            rng: Pcg64::seed_from_u64(seed),
+        }
+    }
+
+    /// Returns the board of the game.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Returns the time left before the game ends.
+    pub fn remaining(&self) -> Duration {
+        self.limit
+            .saturating_sub(self.clock.now().saturating_sub(self.start))
+    }
+
+    /// Returns the time left before the current move runs out, if moves are timed.
+    pub fn move_remaining(&self) -> Option<Duration> {
+        self.move_limit
+            .map(|(limit, _)| limit.saturating_sub(self.clock.now().saturating_sub(self.turn)))
+    }
+
+    /// Returns the time left before either the game ends or the current move runs out.
+    pub fn deadline(&self) -> Duration {
+        match self.move_remaining() {
+            Some(remaining) => remaining.min(self.remaining()),
+            None => self.remaining(),
+        }
+    }
+
+    /// Returns whether the game has ended, i.e. its time has run out or the board can no longer
+    /// be shifted.
+    pub fn is_over(&self) -> bool {
+        self.remaining().is_zero() || self.board.legal_moves().is_empty()
+    }
+
+    /// Plays a movement, returning the events that followed, and begins the next move. Fails if
+    /// the game has ended or the movement cannot be performed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mov` - the movement to play
+    pub fn play(&mut self, mov: Move) -> Result<Vec<Event>, ()> {
+        if self.is_over() {
+            return Err(());
+        }
+        let events = self.board.movement(mov)?;
+        self.turn = self.clock.now();
+        Ok(events)
+    }
+
+    /// Handles the current move running out of time, shifting in a random legal direction or
+    /// forfeiting the move as the game requires, and begins the next move. Nothing is done if the
+    /// move has not run out of time or the game has ended.
+    ///
+    /// # Returns
+    /// * `Some(Some(Direction))` - the move ran out of time and a shift in the direction was played
+    /// * `Some(None)` - the move ran out of time and was forfeited
+    /// * `None` - the move has not run out of time
+    pub fn expire(&mut self) -> Option<Option<Direction>> {
+        let (_, expiry) = self.move_limit?;
+        if !self.move_remaining()?.is_zero() || self.is_over() {
+            return None;
+        }
+        let played = match expiry {
+            Expiry::Random => {
+                let dir = *self.board.legal_moves().choose(&mut self.rng)?;
+                self.board.movement(Move::Shift(dir)).ok()?;
+                Some(dir)
+            }
+            Expiry::Forfeit => {
+                // a forfeit on a full board spawns nothing, but the move is still lost
+                let _ = self.board.pass();
+                None
+            }
+        };
+        self.turn = self.clock.now();
+        Some(played)
+    }
+}
+
+/// Returns a duration as minutes and seconds, e.g. `2:05`, rounding up to the next second so
+/// that no time is shown as remaining only once it has run out.
+///
+/// # Arguments
+///
+/// * `time` - the duration to write
+pub fn format_time(time: Duration) -> String {
+    let secs = time.as_secs() + u64::from(time.subsec_nanos() > 0);
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Runs the `blitz` mode: plays a game interactively against the clock, reading one movement per
+/// line from stdin, until the time limit given by `--time` (in seconds, three minutes by default)
+/// runs out or the board can no longer be shifted. If `--move-time` is given, each move must be
+/// made within that many seconds, or else a random shift is played (or, given
+/// `--expiry forfeit`, a new cell is spawned without shifting). The time left is shown before
+/// every move, and the final score and largest cell are shown at the end.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let seed = args.get::<u64>("seed")?.unwrap_or_else(rand::random);
+    let limit = Duration::from_secs(args.get::<u64>("time")?.unwrap_or(TIME_LIMIT));
+    let move_limit = match args.get::<u64>("move-time")? {
+        Some(secs) => Some((
+            Duration::from_secs(secs),
+            args.get::<Expiry>("expiry")?.unwrap_or(Expiry::Random),
+        )),
+        None => None,
+    };
+
+    let board = Board::with_rules(args.rules()?, seed);
+    let mut blitz = Blitz::new(board, SystemClock::default(), limit, move_limit, seed);
+
+    // stdin is read on another thread, so that the clock can run out while waiting for a move
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lines() {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    loop {
+        println!("{}\n", blitz.board());
+        println!("score: {}", blitz.board().score());
+        match blitz.move_remaining() {
+            Some(move_time) => println!(
+                "time: {} (move: {})\n",
+                format_time(blitz.remaining()),
+                format_time(move_time)
+            ),
+            None => println!("time: {}\n", format_time(blitz.remaining())),
+        }
+        if blitz.is_over() {
+            break;
+        }
+
+        print!("move: ");
+        std::io::stdout().flush().unwrap();
+        match receiver.recv_timeout(blitz.deadline()) {
+            Ok(line) => {
+                let mut input = line.map_err(|e| e.to_string())?;
+                input.truncate(1);
+                let input = input.to_lowercase();
+                match parse_input(&input, &blitz.board().rules().directions()) {
+                    Ok(mov) => {
+                        if blitz.play(mov).is_err() {
+                            println!("invalid move");
+                        }
+                    }
+                    Err(()) => println!("invalid input"),
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                println!();
+                match blitz.expire() {
+                    Some(Some(dir)) => println!("out of time for the move: shifted {}", dir),
+                    Some(None) => println!("out of time for the move: forfeited"),
+                    None => {}
+                }
+            }
+            // stdin was closed, so no more moves can be read
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+        println!();
+    }
+
+    match blitz.remaining().is_zero() {
+        true => println!("time's up\n"),
+        false => println!("game over\n"),
+    }
+    println!("score: {}", blitz.board().score());
+    println!("largest cell: {}", blitz.board().max_value());
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod test;
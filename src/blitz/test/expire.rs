@@ -0,0 +1,40 @@
+use super::*;
+
+/// Affirm that nothing is done before the move runs out of time, or if moves are not timed.
+#[test]
+fn in_time() {
+    let (mut game, clock) = blitz(180, Some((5, Expiry::Random)));
+    clock.advance(4);
+    assert_eq!(None, game.expire());
+
+    let (mut game, clock) = blitz(180, None);
+    clock.advance(60);
+    assert_eq!(None, game.expire());
+}
+
+/// Affirm that a move which runs out of time is played as a shift in a legal direction, which is
+/// the same for the same seed, and that the next move is then begun.
+#[test]
+fn random() {
+    let (mut game, clock) = blitz(180, Some((5, Expiry::Random)));
+    clock.advance(5);
+    let dir = game.expire().unwrap().unwrap();
+    assert!([Direction::Left, Direction::Right, Direction::Down].contains(&dir));
+    assert_ne!("2,2,0,0/0,0,0,0/0,0,0,0/0,0,0,0", game.board().notation());
+    assert_eq!(Some(Duration::from_secs(5)), game.move_remaining());
+
+    let (mut again, clock) = blitz(180, Some((5, Expiry::Random)));
+    clock.advance(5);
+    assert_eq!(Some(Some(dir)), again.expire());
+}
+
+/// Affirm that a forfeited move spawns a cell without shifting the board.
+#[test]
+fn forfeit() {
+    let (mut game, clock) = blitz(180, Some((5, Expiry::Forfeit)));
+    clock.advance(7);
+    assert_eq!(Some(None), game.expire());
+    assert_eq!(0, game.board().score());
+    assert_eq!(13, game.board().count_empty());
+    assert!(game.board().notation().starts_with("2,2,"));
+}
@@ -0,0 +1,10 @@
+use super::*;
+
+/// Affirm that durations are written as minutes and seconds, rounding up to the next second.
+#[test]
+fn minutes_and_seconds() {
+    assert_eq!("3:00", format_time(Duration::from_secs(180)));
+    assert_eq!("0:05", format_time(Duration::from_secs(5)));
+    assert_eq!("1:01", format_time(Duration::from_millis(60_200)));
+    assert_eq!("0:00", format_time(Duration::ZERO));
+}
@@ -0,0 +1,42 @@
+use std::rc::Rc;
+
+use super::*;
+
+mod expire;
+mod format_time;
+mod play;
+
+/// A clock which only moves when it is advanced, shared between its copies. The tests of the other
+/// timed modes are timed by it too.
+#[derive(Clone, Default)]
+pub struct ManualClock(Rc<std::cell::Cell<Duration>>);
+
+/// Implementation of the `Clock` trait for `ManualClock`.
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.0.get()
+    }
+}
+
+impl ManualClock {
+    /// Moves the clock forward by the specified number of seconds.
+    pub fn advance(&self, secs: u64) {
+        self.0.set(self.0.get() + Duration::from_secs(secs));
+    }
+}
+
+/// Starts a timed game of the specified length in seconds, with each move limited to the
+/// specified number of seconds if any, returning the game and the clock it is timed by.
+fn blitz(secs: u64, move_limit: Option<(u64, Expiry)>) -> (Blitz<ManualClock>, ManualClock) {
+    let clock = ManualClock::default();
+    clock.advance(100);
+    let board = "2,2,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().unwrap();
+    let blitz = Blitz::new(
+        board,
+        clock.clone(),
+        Duration::from_secs(secs),
+        move_limit.map(|(secs, expiry)| (Duration::from_secs(secs), expiry)),
+        1,
+    );
+    (blitz, clock)
+}
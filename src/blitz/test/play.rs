@@ -0,0 +1,34 @@
+use super::*;
+
+/// Affirm that the time left runs down with the clock, and that moves are played until it runs
+/// out but not after.
+#[test]
+fn runs_out() {
+    let (mut game, clock) = blitz(180, None);
+    assert_eq!(Duration::from_secs(180), game.remaining());
+    assert_eq!(None, game.move_remaining());
+
+    clock.advance(60);
+    assert_eq!(Duration::from_secs(120), game.remaining());
+    game.play(Move::Shift(Direction::Left)).unwrap();
+    assert_eq!(4, game.board().score());
+    assert!(!game.is_over());
+
+    clock.advance(150);
+    assert_eq!(Duration::ZERO, game.remaining());
+    assert!(game.is_over());
+    game.play(Move::Shift(Direction::Right)).unwrap_err();
+}
+
+/// Affirm that playing a move restarts the time limit of the next move.
+#[test]
+fn restarts_move() {
+    let (mut game, clock) = blitz(180, Some((5, Expiry::Random)));
+    clock.advance(3);
+    assert_eq!(Some(Duration::from_secs(2)), game.move_remaining());
+    assert_eq!(Duration::from_secs(2), game.deadline());
+
+    game.play(Move::Shift(Direction::Left)).unwrap();
+    assert_eq!(Some(Duration::from_secs(5)), game.move_remaining());
+    assert_eq!(Duration::from_secs(177), game.remaining());
+}
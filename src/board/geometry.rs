@@ -0,0 +1,403 @@
+//! Board geometries: the shapes a board may take, deciding which coordinates of the grid hold
+//! cells, in which directions the board can be shifted and which lines of cells are shifted
+//! together in each direction.
+//!
+//! Every geometry is stored in a grid of rows and columns. A hexagonal board of side `n` is
+//! stored in a square grid of `2n - 1` rows and columns using axial coordinates: the cells of
+//! each row are offset by half a cell from those of the row above, so the top-left and
+//! bottom-right corners of the grid lie off the board. Its cells are shifted along its rows
+//! (left and right), along its columns (up-left and down-right) and along the lines on which
+//! `row + col` is constant (up-right and down-left).
+//!
+//! A cube is stored with its layers placed side by side in the grid, from the front layer on the
+//! left to the back layer on the right, so the columns of the grid run through every layer in
+//! turn. Its cells are shifted along the rows and columns of each layer, and through the layers
+//! (in and out) along the lines of cells sharing a row and a column of their layers.
+
+use serde::{Deserialize, Serialize};
+
+use super::{BoardCoord, Rules, BOARD_COLS, BOARD_ROWS};
+use crate::Direction;
+
+/// Defines the number of rows and columns of the grid of a hexagonal board, i.e. a hexagon with
+/// three cells on each side, unless specified otherwise by the `Rules`.
+const HEX_SIDE: usize = 5;
+
+/// Defines the number of layers of a cube, unless specified otherwise by the `Rules`.
+const CUBE_LAYERS: usize = 4;
+
+/// Defines the separator placed between the layers of a cube when it is rendered.
+const LAYER_SEPARATOR: &str = " | ";
+
+/// The shape of a game board and the rules by which its cells are laid out and shifted.
+pub trait Geometry: Send + Sync {
+    /// Returns whether the board can be played with the specified rules, i.e. whether the size
+    /// of its grid and the other rules suit the geometry.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - the rules to check
+    fn fits(&self, rules: &Rules) -> bool;
+
+    /// Returns the number of rows and columns of the board, unless the rules of a game say
+    /// otherwise.
+    fn size(&self) -> (usize, usize);
+
+    /// Returns the number of layers of the board, unless the rules of a game say otherwise.
+    fn layers(&self) -> usize {
+        1
+    }
+
+    /// Returns whether the coordinate of the grid holds a cell of the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - the rules deciding the size of the board
+    /// * `pos` - the coordinate to check
+    fn contains(&self, rules: &Rules, pos: BoardCoord) -> bool;
+
+    /// Returns every direction in which the board can be shifted.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - the rules the board is played by
+    fn directions(&self, rules: &Rules) -> Vec<Direction>;
+
+    /// Returns the coordinates of each line of cells shifted together in the specified
+    /// direction. The cells of each line are listed so that shifting in a reversed direction
+    /// (see `Direction::reversed`) moves them towards the end of the line, and shifting in any
+    /// other direction moves them towards its beginning.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - the rules deciding the size of the board
+    /// * `dir` - the direction in which the lines are to be shifted
+    fn lines(&self, rules: &Rules, dir: Direction) -> Vec<Vec<BoardCoord>>;
+
+    /// Renders the board as text, given the label of each coordinate of its grid, row by row,
+    /// or `None` where the coordinate lies off the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - the rules deciding the size of the board
+    /// * `labels` - the label of each coordinate of the grid
+    fn render(&self, rules: &Rules, labels: Vec<Vec<Option<String>>>) -> String;
+}
+
+/// The representation of each shape a board may take.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Shape {
+    /// A rectangular grid of rows and columns.
+    #[default]
+    Square,
+    /// A hexagon of hexagonal cells.
+    Hex,
+    /// A cube of square layers.
+    Cube,
+}
+
+/// Implementation of the `Display` trait for `Shape`.
+impl std::fmt::Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Shape::Square => "square",
+                Shape::Hex => "hex",
+                Shape::Cube => "cube",
+            }
+        )
+    }
+}
+
+/// Implementation of the `FromStr` trait for `Shape`.
+impl std::str::FromStr for Shape {
+    type Err = ();
+
+    /// Parses a shape from its lowercase name, as written by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Shape::ALL
+            .into_iter()
+            .find(|shape| shape.to_string() == s)
+            .ok_or(())
+    }
+}
+
+impl Shape {
+    /// Every shape a board may take.
+    pub const ALL: [Shape; 3] = [Shape::Square, Shape::Hex, Shape::Cube];
+
+    /// Returns the geometry of the shape.
+    pub fn geometry(self) -> &'static dyn Geometry {
+        match self {
+            Shape::Square => &Square,
+            Shape::Hex => &Hex,
+            Shape::Cube => &Cube,
+        }
+    }
+
+    /// Returns whether the shape is the square grid of the classic game.
+    pub(super) fn is_square(&self) -> bool {
+        *self == Shape::Square
+    }
+}
+
+/// The geometry of the classic game: a rectangular grid, shifted along its rows and columns and,
+/// if the rules allow, its diagonals.
+pub struct Square;
+
+/// Implementation of the `Geometry` trait for `Square`.
+impl Geometry for Square {
+    fn fits(&self, rules: &Rules) -> bool {
+        rules.layers == 1
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (BOARD_ROWS, BOARD_COLS)
+    }
+
+    fn contains(&self, rules: &Rules, pos: BoardCoord) -> bool {
+        pos.0 < rules.rows && pos.1 < rules.cols
+    }
+
+    fn directions(&self, rules: &Rules) -> Vec<Direction> {
+        match rules.diagonal {
+            true => Direction::ALL
+                .into_iter()
+                .chain(Direction::DIAGONALS)
+                .collect(),
+            false => Direction::ALL.to_vec(),
+        }
+    }
+
+    /// The columns, the rows or the diagonals of the board. The cells of each line are listed by
+    /// increasing row (or by increasing column, for rows), so diagonal lines differ in length.
+    fn lines(&self, rules: &Rules, dir: Direction) -> Vec<Vec<BoardCoord>> {
+        let (rows, cols) = (rules.rows, rules.cols);
+        match dir {
+            Direction::Up | Direction::Down => (0..cols)
+                .map(|col| (0..rows).map(|row| (row, col)).collect())
+                .collect(),
+            Direction::Left | Direction::Right => (0..rows)
+                .map(|row| (0..cols).map(|col| (row, col)).collect())
+                .collect(),
+            // diagonals running from the top-left to the bottom-right, along which `col - row` is
+            // constant
+            Direction::UpLeft | Direction::DownRight => (0..rows + cols - 1)
+                .map(|diagonal| {
+                    (0..rows)
+                        .filter_map(|row| {
+                            let col = (row + diagonal).checked_sub(rows - 1)?;
+                            (col < cols).then_some((row, col))
+                        })
+                        .collect()
+                })
+                .collect(),
+            // diagonals running from the top-right to the bottom-left, along which `row + col` is
+            // constant
+            Direction::UpRight | Direction::DownLeft => (0..rows + cols - 1)
+                .map(|sum| {
+                    (0..rows)
+                        .filter(|row| *row <= sum && sum - row < cols)
+                        .map(|row| (row, sum - row))
+                        .collect()
+                })
+                .collect(),
+            Direction::In | Direction::Out => Vec::new(),
+        }
+    }
+
+    /// Writes each row on its own line, with its labels separated by spaces.
+    fn render(&self, _rules: &Rules, labels: Vec<Vec<Option<String>>>) -> String {
+        labels
+            .into_iter()
+            .map(|row| row.into_iter().flatten().collect::<Vec<String>>().join(" "))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// The geometry of the hexagonal variant: a hexagon of hexagonal cells, stored in axial
+/// coordinates and shifted in six directions.
+pub struct Hex;
+
+/// Implementation of the `Geometry` trait for `Hex`.
+impl Geometry for Hex {
+    /// The grid must be square with an odd side and a single layer, and the board can neither
+    /// wrap nor be shifted along the diagonals of a square grid.
+    fn fits(&self, rules: &Rules) -> bool {
+        rules.rows == rules.cols
+            && rules.rows % 2 == 1
+            && rules.layers == 1
+            && !rules.wrap
+            && !rules.diagonal
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (HEX_SIDE, HEX_SIDE)
+    }
+
+    fn contains(&self, rules: &Rules, pos: BoardCoord) -> bool {
+        let radius = rules.rows / 2;
+        pos.0 < rules.rows && pos.1 < rules.cols && (radius..=3 * radius).contains(&(pos.0 + pos.1))
+    }
+
+    fn directions(&self, _rules: &Rules) -> Vec<Direction> {
+        vec![
+            Direction::Left,
+            Direction::Right,
+            Direction::UpLeft,
+            Direction::UpRight,
+            Direction::DownLeft,
+            Direction::DownRight,
+        ]
+    }
+
+    /// The rows, the columns or the lines along which `row + col` is constant, holding only the
+    /// coordinates on the board. The cells of each line are listed by increasing row (or by
+    /// increasing column, for rows).
+    fn lines(&self, rules: &Rules, dir: Direction) -> Vec<Vec<BoardCoord>> {
+        let side = rules.rows;
+        let lines: Vec<Vec<BoardCoord>> = match dir {
+            Direction::Left | Direction::Right => (0..side)
+                .map(|row| (0..side).map(|col| (row, col)).collect())
+                .collect(),
+            Direction::UpLeft | Direction::DownRight => (0..side)
+                .map(|col| (0..side).map(|row| (row, col)).collect())
+                .collect(),
+            Direction::UpRight | Direction::DownLeft => (0..2 * side - 1)
+                .map(|sum| {
+                    (0..side)
+                        .filter(|row| *row <= sum && sum - row < side)
+                        .map(|row| (row, sum - row))
+                        .collect()
+                })
+                .collect(),
+            Direction::Up | Direction::Down | Direction::In | Direction::Out => Vec::new(),
+        };
+        lines
+            .into_iter()
+            .map(|line| {
+                line.into_iter()
+                    .filter(|pos| self.contains(rules, *pos))
+                    .collect::<Vec<BoardCoord>>()
+            })
+            .filter(|line| !line.is_empty())
+            .collect()
+    }
+
+    /// Writes each row on its own line, with its labels padded to a common width and the rows
+    /// indented by half a cell for each row they lie away from the middle row, so that each
+    /// cell lies between its neighbours in the rows above and below.
+    fn render(&self, _rules: &Rules, labels: Vec<Vec<Option<String>>>) -> String {
+        let width = labels
+            .iter()
+            .flatten()
+            .flatten()
+            .map(String::len)
+            .max()
+            .unwrap_or(1);
+        // cells are an even number of characters apart (counting the space between them), so that
+        // half a cell is a whole number of characters
+        let width = width | 1;
+        let middle = labels.len() / 2;
+        labels
+            .into_iter()
+            .enumerate()
+            .map(|(row, labels)| {
+                let indent = " ".repeat(row.abs_diff(middle) * (width + 1) / 2);
+                let cells = labels
+                    .into_iter()
+                    .flatten()
+                    .map(|label| format!("{:>width$}", label, width = width))
+                    .collect::<Vec<String>>();
+                indent + &cells.join(" ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// The geometry of the cube variant: square layers stacked one behind another, shifted in six
+/// directions.
+pub struct Cube;
+
+/// Implementation of the `Geometry` trait for `Cube`.
+impl Geometry for Cube {
+    /// The board cannot be shifted along the diagonals of its layers.
+    fn fits(&self, rules: &Rules) -> bool {
+        !rules.diagonal
+    }
+
+    fn size(&self) -> (usize, usize) {
+        (BOARD_ROWS, BOARD_COLS)
+    }
+
+    fn layers(&self) -> usize {
+        CUBE_LAYERS
+    }
+
+    fn contains(&self, rules: &Rules, pos: BoardCoord) -> bool {
+        let (rows, cols) = rules.grid_size();
+        pos.0 < rows && pos.1 < cols
+    }
+
+    fn directions(&self, _rules: &Rules) -> Vec<Direction> {
+        Direction::ALL.into_iter().chain(Direction::DEPTH).collect()
+    }
+
+    /// The columns or the rows of each layer, or the lines of cells sharing a row and a column of
+    /// their layers. The cells of each line are listed by increasing row, column or layer.
+    fn lines(&self, rules: &Rules, dir: Direction) -> Vec<Vec<BoardCoord>> {
+        let (rows, cols, layers) = (rules.rows, rules.cols, rules.layers);
+        let at = move |row: usize, col: usize, layer: usize| (row, layer * cols + col);
+        match dir {
+            Direction::Up | Direction::Down => (0..layers)
+                .flat_map(|layer| {
+                    (0..cols).map(move |col| (0..rows).map(|row| at(row, col, layer)).collect())
+                })
+                .collect(),
+            Direction::Left | Direction::Right => (0..layers)
+                .flat_map(|layer| {
+                    (0..rows).map(move |row| (0..cols).map(|col| at(row, col, layer)).collect())
+                })
+                .collect(),
+            Direction::In | Direction::Out => (0..rows)
+                .flat_map(|row| {
+                    (0..cols).map(move |col| (0..layers).map(|layer| at(row, col, layer)).collect())
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Writes the layers side by side, from the front layer on the left, with the labels padded
+    /// to a common width so that the layers line up.
+    fn render(&self, rules: &Rules, labels: Vec<Vec<Option<String>>>) -> String {
+        let width = labels
+            .iter()
+            .flatten()
+            .flatten()
+            .map(String::len)
+            .max()
+            .unwrap_or(1);
+        labels
+            .into_iter()
+            .map(|row| {
+                let cells = row
+                    .into_iter()
+                    .flatten()
+                    .map(|label| format!("{:>width$}", label, width = width))
+                    .collect::<Vec<String>>();
+                cells
+                    .chunks(rules.cols)
+                    .map(|layer| layer.join(" "))
+                    .collect::<Vec<String>>()
+                    .join(LAYER_SEPARATOR)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
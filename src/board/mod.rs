@@ -1,19 +1,36 @@
-use std::collections::HashMap;
-
 use array2d::Array2D;
-use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
 
+use crate::cell::{MergeRule, Special, Value};
 use crate::{Cell, Direction, Move};
 
-/// Defines the number of columns in the board.
+mod geometry;
+mod notation;
+mod rules;
+mod save;
+mod spawner;
+
+pub use geometry::Shape;
+pub use rules::{Rules, Variant};
+pub use spawner::{Adversarial, Scripted};
+use spawner::{Random, Spawner};
+
+/// Defines the number of columns in the board, unless specified otherwise by the `Rules`.
 const BOARD_COLS: usize = 4;
 
-/// Defines the number or rows in the board.
+/// Defines the number or rows in the board, unless specified otherwise by the `Rules`.
 const BOARD_ROWS: usize = 4;
 
-/// Defines the maximum number of undos the player can perform.
+/// Defines the maximum number of undos the player can perform, unless specified otherwise by the
+/// `Rules`.
 const HISTORY_SIZE: usize = 1;
 
+/// Defines the cell value that must be reached to win the game, unless specified otherwise by the
+/// `Rules`.
+const WIN_VALUE: usize = 2048;
+
 /// Type representing a cell on the board.
 type BoardCell = Option<Cell>;
 
@@ -21,82 +38,145 @@ type BoardCell = Option<Cell>;
 type BoardGrid = Array2D<BoardCell>;
 
 /// Type representing a `BoardGrid` position (i.e. row, column indices).
-type BoardCoord = (usize, usize);
+pub type BoardCoord = (usize, usize);
+
+/// The representation of the state of play of a game.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// The game can be continued and the winning value has not been reached.
+    Playing,
+    /// A cell has reached the winning value.
+    Won,
+    /// No shift can be performed and the winning value was never reached.
+    Lost,
+}
+
+/// The representation of a change made to the board by a movement.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Event {
+    /// A cell slid from one coordinate to another.
+    Slide { from: BoardCoord, to: BoardCoord },
+    /// A cell slid from one coordinate into the cell at another, merging to form `cell`.
+    Merge {
+        from: BoardCoord,
+        into: BoardCoord,
+        cell: Cell,
+    },
+    /// A cell slid from one coordinate into the cell at another, clearing both (i.e. one of them
+    /// was a bomb).
+    Clear { from: BoardCoord, into: BoardCoord },
+    /// A new cell was spawned at the coordinate.
+    Spawn { at: BoardCoord, cell: Cell },
+}
+
+/// The representation of the path travelled by a cell of a group during a shift, given as
+/// indices within the group.
+struct Travel {
+    from: usize,
+    to: usize,
+    /// Whether the cell merges into the cell at its destination.
+    merged: bool,
+    /// Whether the merge clears both cells, leaving the destination free for the cells behind.
+    cleared: bool,
+}
 
 /// The representation of a game board.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(into = "save::Save", try_from = "save::Save")]
 pub struct Board {
+    /// The rules the game is played by.
+    rules: Rules,
     /// The grid containing the cells of the board.
     grid: BoardGrid,
-    /// The saved, past states of the board that can be.
-    history: Vec<BoardGrid>,
-    /// The calculated boards for shifts in each direction.
-    next: HashMap<Direction, Option<BoardGrid>>,
+    /// The saved, past states of the board (and their scores) that can be restored.
+    history: Vec<(BoardGrid, usize)>,
+    /// The sum of the values of every cell produced by a merge.
+    score: usize,
+    /// The source of randomness for spawned cells.
+    rng: Pcg64,
+    /// The strategy choosing the cells spawned after each shift. It is not saved, so a restored
+    /// board spawns cells at random.
+    spawner: Box<dyn Spawner>,
 }
 
 /// Implementation of the `Default` trait for `Board`.
 impl Default for Board {
     /// Create an empty grid and an empty, bound-vector of grid states.
     fn default() -> Self {
-        Self {
-            grid: Array2D::filled_with(None, BOARD_ROWS, BOARD_COLS),
-            history: Vec::with_capacity(HISTORY_SIZE),
-            next: HashMap::new(),
-        }
+        Board::empty(Rules::default())
     }
 }
 
 /// Implementation of the `Display` trait for `Board`.
 impl std::fmt::Display for Board {
+    /// Writes the board as rendered by the geometry of its shape, with each cell written as its
+    /// value, `0` when empty or `#` when it is a wall.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.grid
-                .rows_iter()
-                .map(|row| row
-                    .map(|cell| match cell {
-                        Some(c) => c.to_string(),
-                        None => 0.to_string(),
+        let (rows, cols) = self.rules.grid_size();
+        let labels = (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(|col| match self.grid.get(row, col).unwrap() {
+                        _ if !self.rules.contains((row, col)) => None,
+                        Some(c) => Some(c.to_string()),
+                        None if self.rules.is_wall((row, col)) => Some("#".to_string()),
+                        None => Some(0.to_string()),
                     })
-                    .reduce(|a, b| a + " " + &b)
-                    .unwrap())
-                .reduce(|a, b| a + "\n" + &b)
-                .unwrap()
-        )
+                    .collect()
+            })
+            .collect();
+        write!(f, "{}", self.rules.geometry().render(&self.rules, labels))
     }
 }
 
 impl Board {
-    /// Retrieves all cells matching the specified emptiness.
+    /// Retrieves all cells matching the specified emptiness. Walls and coordinates lying off the
+    /// board are neither empty nor occupied.
     ///
     /// # Arguments
     ///
     /// * `is_empty` - whether the cell should be empty; search criteria
-    fn get_cells_by_emptiness(&self, is_empty: bool) -> impl Iterator<Item = BoardCoord> + '_ {
-        (0..BOARD_COLS)
-            .flat_map(|y| (0..BOARD_ROWS).map(move |x| (x, y)))
+    pub fn get_cells_by_emptiness(&self, is_empty: bool) -> impl Iterator<Item = BoardCoord> + '_ {
+        let (rows, cols) = self.rules.grid_size();
+        (0..cols)
+            .flat_map(move |y| (0..rows).map(move |x| (x, y)))
+            .filter(move |p| self.rules.contains(*p) && !self.rules.is_wall(*p))
             .filter(move |p| is_empty == self.grid.get(p.0, p.1).unwrap().is_none())
     }
 
-    /// Attempts to spawn a new cell on the game board at the specified location.
+    /// Attempts to place the specified cell on the game board at the specified location, failing
+    /// if the location is occupied, a wall or off the board.
     ///
     /// # Arguments
     ///
-    /// * `pos` - the grid coordinate at which to spawn
-    fn spawn_at(&mut self, pos: BoardCoord) -> Result<(), ()> {
-        assert!(pos.0 < BOARD_ROWS);
-        assert!(pos.1 < BOARD_COLS);
-
-        match self.grid.get(pos.0, pos.1).unwrap()
-        {
+    /// * `pos` - the grid coordinate at which to place the cell
+    /// * `cell` - the cell to place
+    pub fn place(&mut self, pos: BoardCoord, cell: Cell) -> Result<(), ()> {
+        match self.grid.get(pos.0, pos.1).ok_or(())? {
             Some(_) => Err(()),
+            None if self.rules.is_wall(pos) || !self.rules.contains(pos) => Err(()),
             None => {
-                self.grid.set(pos.0, pos.1, Some(Cell::default())).unwrap();
+                self.grid.set(pos.0, pos.1, Some(cell)).unwrap();
                 Ok(())
             }
         }
     }
 
+    /// Attempts to spawn a new cell on the game board at the specified location.
+    ///
+    /// # Arguments
+    ///
+    /// * `pos` - the grid coordinate at which to spawn
+    fn spawn_at(&mut self, pos: BoardCoord) -> Result<(), ()> {
+        assert!(pos.0 < self.rules.grid_size().0);
+        assert!(pos.1 < self.rules.grid_size().1);
+
+        let cell = self.rules.spawn_cell(&mut self.rng);
+        self.place(pos, cell)
+    }
+
     #[allow(dead_code)]
     /// Spawns new cells on the game board at the specified location(s).
     ///
@@ -110,21 +190,49 @@ impl Board {
         Ok(())
     }
 
-    /// Randomly spawns a new cell on the game board.
-    fn spawn(&mut self) -> Result<(), ()> {
-        let mut rng = rand::thread_rng();
-        let empty_coords = self
-            .get_cells_by_emptiness(true)
-            .collect::<Vec<BoardCoord>>();
-        let chosen = empty_coords.choose(&mut rng);
-
-        match chosen {
-            Some(coord) => self.spawn_at(*coord),
-            None => Err(()),
+    /// Spawns a new cell on the game board as chosen by its spawner, returning the location it
+    /// was spawned at. Fails if the spawner spawns nothing, e.g. when the board is full.
+    fn spawn(&mut self) -> Result<BoardCoord, ()> {
+        // the spawner looks at the board, so it and the random number generator are set aside
+        let mut spawner = std::mem::replace(&mut self.spawner, Box::new(Random));
+        let mut rng = self.rng.clone();
+        let chosen = spawner.spawn(self, &mut rng);
+        self.spawner = spawner;
+        self.rng = rng;
+
+        let (pos, cell) = chosen.ok_or(())?;
+        self.place(pos, cell).map(|_| pos)
+    }
+
+    /// Replaces the strategy choosing the cells spawned after each shift, which spawns cells at
+    /// random unless replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `spawner` - the strategy to spawn cells by
+    pub fn set_spawner(&mut self, spawner: impl Spawner + 'static) {
+        self.spawner = Box::new(spawner);
+    }
+
+    /// Returns an empty game board, played by the specified rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - the rules the game is played by
+    fn empty(mut rules: Rules) -> Self {
+        rules.normalize();
+        Self {
+            grid: Array2D::filled_with(None, rules.grid_size().0, rules.grid_size().1),
+            history: Vec::new(),
+            score: 0,
+            rng: Pcg64::from_entropy(),
+            spawner: Box::new(Random),
+            rules,
         }
     }
 
     /// Returns a new instance of a game board.
+    #[allow(dead_code)]
     pub fn new() -> Self {
         let mut inst = Board::default();
         inst.spawn()
@@ -132,30 +240,196 @@ impl Board {
         inst
     }
 
-    fn get_mergeable<'a>(cells: impl IntoIterator<Item = &'a BoardCell>) -> Vec<(usize, usize)> {
-        struct RefCell {
-            index: Option<usize>,
-            value: Option<usize>,
-        }
+    /// Returns a new instance of a game board whose spawned cells are entirely determined by the
+    /// specified seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - the seed of the random number generator used for spawning cells
+    pub fn with_seed(seed: u64) -> Self {
+        Board::with_rules(Rules::default(), seed)
+    }
 
-        let mut result: Vec<(usize, usize)> = Vec::new();
-        let mut rc = RefCell {
-            index: None,
-            value: None,
+    /// Returns a new instance of a game board, played by the specified rules, whose spawned cells
+    /// are entirely determined by the specified seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - the rules the game is played by
+    /// * `seed` - the seed of the random number generator used for spawning cells
+    pub fn with_rules(rules: Rules, seed: u64) -> Self {
+        let mut inst = Board {
+            rng: Pcg64::seed_from_u64(seed),
+            ..Board::empty(rules)
         };
+        inst.spawn()
+            .expect("failed to spawn a cell on the empty board");
+        inst
+    }
 
-        for (idx, cell_opt) in cells.into_iter().enumerate() {
-            if let Some(cc) = cell_opt {
-                let val = cc.value();
-
-                if rc.index.is_some() && rc.value.is_some() && val == rc.value.unwrap() {
-                    result.push((rc.index.unwrap(), idx));
-                    rc.index = None;
-                    rc.value = None;
-                } else {
-                    rc.index = Some(idx);
-                    rc.value = Some(val);
+    /// Returns the rules the game is played by.
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// Returns the current score of the game, which saturates at `usize::MAX` rather than
+    /// overflowing.
+    pub fn score(&self) -> usize {
+        self.score
+    }
+
+    /// Returns every cell, row by row.
+    pub fn cells(&self) -> Vec<Vec<BoardCell>> {
+        self.grid.as_rows()
+    }
+
+    /// Returns whether there is a previous state of the board that can be restored by undoing.
+    pub fn can_undo(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    /// Returns the number of empty cells on the board.
+    pub fn count_empty(&self) -> usize {
+        self.get_cells_by_emptiness(true).count()
+    }
+
+    /// Returns the number of occupied cells on the board.
+    pub fn count_filled(&self) -> usize {
+        self.get_cells_by_emptiness(false).count()
+    }
+
+    /// Returns the largest value held by a cell on the board, or zero if the board is empty. The
+    /// value saturates at `usize::MAX`, as the values of cells are unbounded.
+    pub fn max_value(&self) -> usize {
+        self.grid
+            .elements_row_major_iter()
+            .flatten()
+            .map(Cell::value)
+            .max()
+            .map_or(0, crate::cell::saturate)
+    }
+
+    /// Returns the directions in which the board can currently be shifted.
+    pub fn legal_moves(&self) -> Vec<Direction> {
+        self.rules
+            .directions()
+            .into_iter()
+            .filter(|dir| self.shifted(*dir).is_some())
+            .collect()
+    }
+
+    /// Returns the state of play of the game.
+    pub fn status(&self) -> Status {
+        if self.max_value() >= self.rules.win_value {
+            Status::Won
+        } else if self.legal_moves().is_empty() {
+            Status::Lost
+        } else {
+            Status::Playing
+        }
+    }
+
+    /// Finds the runs of cells of a group which merge when the group is shifted towards its
+    /// beginning. Each run holds as many non-empty cells as the merge rule requires (or two, if
+    /// either of its first two cells is special), read in order and skipping empty cells; a cell
+    /// belongs to at most one run, and runs are taken greedily from the beginning of the group.
+    ///
+    /// # Arguments
+    /// * `cells` - the group of `BoardCell`s, ordered from the edge being shifted towards
+    /// * `rule` - the merge rule deciding which cells merge
+    ///
+    /// # Returns
+    /// The indices of the cells of each run, the first being the cell merged into.
+    fn get_mergeable<'a>(
+        cells: impl IntoIterator<Item = &'a BoardCell>,
+        rule: &dyn MergeRule,
+    ) -> Vec<Vec<usize>> {
+        let filled = cells
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, cell)| cell.as_ref().map(|c| (idx, c)))
+            .collect::<Vec<(usize, &Cell)>>();
+        let special = |i: usize| filled.get(i).is_some_and(|(_, c)| c.special().is_some());
+
+        let mut result = Vec::new();
+        let mut start = 0;
+        loop {
+            let len = match special(start) || special(start + 1) {
+                true => 2,
+                false => rule.run(),
+            };
+            let Some(run) = filled.get(start..start + len) else {
+                break;
+            };
+            let cells = run.iter().map(|(_, cell)| *cell).collect::<Vec<&Cell>>();
+            match Cell::merged(&cells, rule) {
+                Some(_) => {
+                    result.push(run.iter().map(|(idx, _)| *idx).collect());
+                    start += len;
                 }
+                None => start += 1,
+            }
+        }
+        result
+    }
+
+    /// Calculates the path each cell of a group would travel if the group were shifted. Cells
+    /// which would not move are omitted.
+    ///
+    /// # Arguments
+    /// * `cells` - the group of `BoardCell`s to be shifted
+    /// * `dir` - the direction in which the group would be shifted
+    /// * `rule` - the merge rule deciding which cells merge
+    fn plan_group(cells: &[BoardCell], dir: Direction, rule: &dyn MergeRule) -> Vec<Travel> {
+        // the group is considered in the order it is shifted towards, as in `shift_group`
+        let reverse = dir.reversed();
+        let index = |i: usize| match reverse {
+            true => cells.len() - 1 - i,
+            false => i,
+        };
+        let ordered = (0..cells.len())
+            .map(|i| &cells[index(i)])
+            .collect::<Vec<&BoardCell>>();
+        let mergeable = Board::get_mergeable(ordered.iter().copied(), rule);
+
+        // a run which clears leaves its destination free for the cells behind it
+        let cleared = mergeable
+            .iter()
+            .filter(|run| {
+                let run = run
+                    .iter()
+                    .map(|idx| ordered[*idx].as_ref().unwrap())
+                    .collect::<Vec<&Cell>>();
+                Cell::merged(&run, rule) == Some(None)
+            })
+            .map(|run| run[0])
+            .collect::<Vec<usize>>();
+
+        let mut result = Vec::new();
+        let mut dests: Vec<Option<usize>> = vec![None; cells.len()];
+        let mut next = 0;
+
+        for (idx, cell) in ordered.iter().enumerate() {
+            if cell.is_none() {
+                continue;
+            }
+            let (to, merged, clears) = match mergeable.iter().find(|run| run[1..].contains(&idx)) {
+                Some(run) => (dests[run[0]].unwrap(), true, cleared.contains(&run[0])),
+                None => {
+                    dests[idx] = Some(next);
+                    if !cleared.contains(&idx) {
+                        next += 1;
+                    }
+                    (dests[idx].unwrap(), false, false)
+                }
+            };
+            if to != idx {
+                result.push(Travel {
+                    from: index(idx),
+                    to: index(to),
+                    merged,
+                    cleared: clears,
+                });
             }
         }
         result
@@ -166,6 +440,7 @@ impl Board {
     /// # Arguments
     /// * `cells` - the group of `BoardCell`s to be shifted
     /// * `dir` - the direction in which to shift the group
+    /// * `rule` - the merge rule deciding which cells merge
     ///
     /// # Returns
     /// * `None` - neither a shift or merge was able to be performed on the group
@@ -173,107 +448,256 @@ impl Board {
     fn shift_group(
         cells: impl IntoIterator<Item = BoardCell>,
         dir: Direction,
+        rule: &dyn MergeRule,
     ) -> Option<Vec<BoardCell>> {
         let mut result = cells.into_iter().collect::<Vec<BoardCell>>();
-        let mergeable = Board::get_mergeable(result.iter());
-        let mut valid = !mergeable.is_empty();
 
-        /* merge pairs */
+        // shifting towards the end of the group is performed as a shift towards the beginning of
+        // the reversed group
+        let reverse = dir.reversed();
+        if reverse {
+            result.reverse();
+        }
+
+        let mergeable = Board::get_mergeable(result.iter(), rule);
+        let mut valid = !mergeable.is_empty();
 
-        for pair in mergeable {
-            let (ls, rs) = result.split_at_mut(pair.1);
-            let mergee = ls[pair.0].as_mut().unwrap();
+        /* merge runs */
 
+        for run in mergeable {
             // note: `take` replaces the value with `None`
-            let merger = rs[0].take().unwrap();
-
-            mergee.merge(merger).unwrap();
+            let mergers = run[1..]
+                .iter()
+                .map(|idx| result[*idx].take().unwrap())
+                .collect::<Vec<Cell>>();
+            let mergee = result[run[0]].take().unwrap();
+
+            // the mergee is left empty if the cells clear each other
+            result[run[0]] = mergee.merge(mergers, rule).unwrap();
         }
 
         /* shift cells */
 
         let mut swpidx: Option<usize> = None;
-        let iter = match dir {
-            Direction::Up | Direction::Left => 0..result.len(),
-            Direction::Down | Direction::Right => result.len()..0,
-        };
 
-        for idx in iter {
-            match (swpidx.is_some(), result[idx].is_some()) {
+        for idx in 0..result.len() {
+            match (swpidx, result[idx].is_some()) {
                 // if `swpidx` isn't set and value is `None`, set the `swpidx`
-                (false, false) => {
+                (None, false) => {
                     swpidx = Some(idx);
                 }
-                // if `swpidx` is set and value is `Some(...)`, perform swap
-                (true, true) => {
-                    result.swap(swpidx.unwrap(), idx);
-                    swpidx = None;
+                // if `swpidx` is set and value is `Some(...)`, perform swap; every cell between
+                // `swpidx` and `idx` is empty, so the next empty cell directly follows `swpidx`
+                (Some(swp), true) => {
+                    result.swap(swp, idx);
+                    swpidx = Some(swp + 1);
                     valid = true;
                 }
                 _ => {}
             }
         }
 
-        valid.then(|| result)
+        if reverse {
+            result.reverse();
+        }
+
+        valid.then_some(result)
     }
 
-    // TODO: add test suite
     /// Attempt to undo the board to the previous move state.
     fn undo(&mut self) -> Result<(), ()> {
-        let state = self.history.pop().ok_or(())?;
-        self.grid = state;
+        let (grid, score) = self.history.pop().ok_or(())?;
+        self.grid = grid;
+        self.score = score;
         Ok(())
     }
 
-    fn shift(&mut self, dir: Direction) -> Result<(), ()> {
-        let mut copy = self.grid.clone();
-        let mut shifted = false;
-
-        match dir {
-            // TODO: determine an API modification to get the following commented-out code working
-            Direction::Up | Direction::Down => {
-                let mut columns = self.grid.columns_iter();
-                for col in columns {
-                    // if let Some(group) = Board::shift_group(col, dir) {
-                        // shifted = true;
-                        // ...
-                    // }
+    /// Returns the board that results from shifting in the specified direction, without spawning
+    /// a new cell or recording history.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - the direction in which to shift
+    pub fn shifted(&self, dir: Direction) -> Option<Board> {
+        let mut copy = self.clone();
+        copy.shift(dir).ok().map(|_| copy)
+    }
+
+    /// Rotates a group of coordinates which wraps around the board so that it can be shifted as a
+    /// linear group. A group holding a wall is rotated to begin just after the wall, so that the
+    /// segment spanning the edges of the board is kept whole. Otherwise, the group is read from
+    /// its front edge as usual, unless its first cell can complete a run with its last cells and
+    /// would not otherwise merge, in which case it is read starting at the first cell of that
+    /// run: the first cell is then pushed over the edge to merge with the last.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - the coordinates of the group, in order
+    /// * `dir` - the direction in which the group is to be shifted
+    fn cycle(&self, mut group: Vec<BoardCoord>, dir: Direction) -> Vec<BoardCoord> {
+        if let Some(wall) = group.iter().position(|pos| self.rules.is_wall(*pos)) {
+            group.rotate_left(wall + 1);
+            return group;
+        }
+
+        // the group is considered in the order it is shifted towards, as in `shift_group`
+        let reverse = dir.reversed();
+        if reverse {
+            group.reverse();
+        }
+
+        let cells = group
+            .iter()
+            .map(|pos| self.grid.get(pos.0, pos.1).unwrap())
+            .collect::<Vec<&BoardCell>>();
+        let rule = self.rules.variant.rule();
+        let filled = (0..cells.len())
+            .filter(|idx| cells[*idx].is_some())
+            .collect::<Vec<usize>>();
+        if let (Some(first), Some(last)) = (filled.first(), filled.last()) {
+            // the run spanning the edge is made of the last cells followed by the first
+            let special = |idx: &usize| cells[*idx].as_ref().unwrap().special().is_some();
+            let len = match special(first) || special(last) {
+                true => 2,
+                false => rule.run(),
+            };
+            if filled.len() >= len {
+                let tail = &filled[filled.len() + 1 - len..];
+                let merged = Board::get_mergeable(cells.iter().copied(), rule)
+                    .iter()
+                    .any(|run| run.contains(first));
+                let run = tail
+                    .iter()
+                    .chain([first])
+                    .map(|idx| cells[*idx].as_ref().unwrap())
+                    .collect::<Vec<&Cell>>();
+                if Cell::merged(&run, rule).is_some() && !merged {
+                    group.rotate_left(tail[0]);
                 }
             }
-            Direction::Left | Direction::Right => {
-                let mut rows = self.grid.rows_iter();
-                for row in rows {
-                    // if let Some(group) = Board::shift_group(row, dir) {
-                        // shifted = true;
-                        // ...
-                    // }
+        }
+
+        if reverse {
+            group.reverse();
+        }
+        group
+    }
+
+    fn shift(&mut self, dir: Direction) -> Result<Vec<Event>, ()> {
+        let copy = (self.grid.clone(), self.score);
+        let mut events = Vec::new();
+
+        if !self.rules.directions().contains(&dir) {
+            return Err(());
+        }
+
+        // walls split each group into segments which are shifted independently
+        let segments = self
+            .rules
+            .geometry()
+            .lines(&self.rules, dir)
+            .into_iter()
+            .map(|group| match self.rules.wrap {
+                true => self.cycle(group, dir),
+                false => group,
+            })
+            .collect::<Vec<Vec<BoardCoord>>>();
+        let segments = segments
+            .iter()
+            .flat_map(|group| group.split(|pos| self.rules.is_wall(*pos)))
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.to_vec())
+            .collect::<Vec<Vec<BoardCoord>>>();
+
+        for coords in segments {
+            let group = coords
+                .iter()
+                .map(|pos| self.grid.get(pos.0, pos.1).unwrap().clone())
+                .collect::<Vec<BoardCell>>();
+            let rule = self.rules.variant.rule();
+            let travels = Board::plan_group(&group, dir, rule);
+
+            if let Some(group) = Board::shift_group(group, dir, rule) {
+                for (pos, cell) in coords.iter().zip(group) {
+                    self.grid.set(pos.0, pos.1, cell).unwrap();
+                }
+                // a run of more than two cells merges into its destination once per merger, but is
+                // only scored once
+                let mut scored = Vec::new();
+                for travel in travels {
+                    let (from, to) = (coords[travel.from], coords[travel.to]);
+                    events.push(match (travel.merged, travel.cleared) {
+                        (true, true) => Event::Clear { from, into: to },
+                        (true, false) => {
+                            let cell = self.grid.get(to.0, to.1).unwrap().clone().unwrap();
+                            if !scored.contains(&to) {
+                                scored.push(to);
+                                // the score saturates rather than overflowing
+                                let points = crate::cell::saturate(&rule.score(cell.value()));
+                                self.score = self.score.saturating_add(points);
+                            }
+                            Event::Merge {
+                                from,
+                                into: to,
+                                cell,
+                            }
+                        }
+                        (false, _) => Event::Slide { from, to },
+                    });
                 }
             }
-        };
+        }
 
-        match shifted {
-            true => {
-                // push the unmodified copy into history
-                self.history.push(copy);
-                Ok(())
-            },
+        match events.is_empty() {
             false => {
-                // revert the grid state to the unmodified copy
-                self.grid = copy;
-                Err(())
+                self.remember(copy);
+                Ok(events)
             }
+            true => Err(()),
         }
     }
 
-    /// Handles movement on the game board.
+    /// Pushes an unmodified copy of the board into history, forgetting the oldest state if full.
+    ///
+    /// # Arguments
+    ///
+    /// * `copy` - the grid and score of the board before it was modified
+    fn remember(&mut self, copy: (BoardGrid, usize)) {
+        if self.rules.undo_limit > 0 {
+            if self.history.len() == self.rules.undo_limit {
+                self.history.remove(0);
+            }
+            self.history.push(copy);
+        }
+    }
+
+    /// Spawns a new cell without shifting the board, as when a move is forfeited, returning the
+    /// events that followed. The board can be undone to its state before the cell was spawned.
+    pub fn pass(&mut self) -> Result<Vec<Event>, ()> {
+        let copy = (self.grid.clone(), self.score);
+        let at = self.spawn()?;
+        self.remember(copy);
+        let cell = self.grid.get(at.0, at.1).unwrap().clone().unwrap();
+        Ok(vec![Event::Spawn { at, cell }])
+    }
+
+    /// Handles movement on the game board, returning the events that followed the movement.
     ///
     /// # Arguments
     ///
     /// * `mov` - the movement type to handle
-    pub fn movement(&mut self, mov: Move) -> Result<(), ()> {
+    pub fn movement(&mut self, mov: Move) -> Result<Vec<Event>, ()> {
         match mov {
-            Move::Shift(dir) => self.shift(dir),
-            Move::Undo => self.undo(),
+            Move::Shift(dir) => {
+                let mut events = self.shift(dir)?;
+                // the spawner may spawn nothing, e.g. once its script has run out
+                if let Ok(at) = self.spawn() {
+                    let cell = self.grid.get(at.0, at.1).unwrap().clone().unwrap();
+                    events.push(Event::Spawn { at, cell });
+                }
+                Ok(events)
+            }
+            Move::Undo => self.undo().map(|_| Vec::new()),
         }
     }
 }
@@ -0,0 +1,193 @@
+//! Board notation: a single-line, whitespace-free textual form of the grid.
+//!
+//! Rows are listed from top to bottom and separated by `/`; the cells of a row are listed from
+//! left to right and separated by `,`. Each cell is written as its value, `0` when empty, `#`
+//! when it is a wall or the symbol of its kind when it is special (`J` for a joker, `B` for a
+//! bomb and `D` for a doubler). For example, a board holding a two in the top-left corner, a four
+//! in the bottom-right corner and a wall as the second cell of the third row is written as
+//! `2,0,0,0/0,0,0,0/0,#,0,0/0,0,0,4`. The size of the board is given by the number of rows and
+//! cells written, and every row must hold the same number of cells.
+//!
+//! Coordinates of the grid which lie off the board, such as the corners of the grid of a
+//! hexagonal board, are written as `.`; e.g. the smallest hexagonal board holding a two in its
+//! middle is written as `.,0,0/0,2,0/0,0,.`.
+
+use super::*;
+
+/// Defines the separator placed between the rows of the grid.
+const ROW_SEPARATOR: char = '/';
+
+/// Defines the separator placed between the cells of a row.
+const CELL_SEPARATOR: char = ',';
+
+/// Defines the symbol written in place of a wall.
+const WALL: &str = "#";
+
+/// Defines the symbol written in place of a coordinate lying off the board.
+const VOID: &str = ".";
+
+/// Type representing a grid read from notation, with the coordinates of its walls and of the
+/// coordinates lying off the board.
+type ReadGrid = (BoardGrid, Vec<BoardCoord>, Vec<BoardCoord>);
+
+/// Parses a grid, the coordinates of its walls and the coordinates lying off the board from its
+/// notation.
+///
+/// # Arguments
+///
+/// * `s` - the notation of the grid
+/// * `rule` - the merge rule deciding which values cells may hold
+pub(super) fn read_grid(s: &str, rule: &dyn MergeRule) -> Result<ReadGrid, ()> {
+    let mut walls = Vec::new();
+    let mut voids = Vec::new();
+    let rows = s
+        .split(ROW_SEPARATOR)
+        .enumerate()
+        .map(|(row, cells)| {
+            cells
+                .split(CELL_SEPARATOR)
+                .enumerate()
+                .map(|(col, cell)| {
+                    if cell == WALL {
+                        walls.push((row, col));
+                        return Ok(None);
+                    }
+                    if cell == VOID {
+                        voids.push((row, col));
+                        return Ok(None);
+                    }
+                    if let Some(special) = Special::ALL
+                        .into_iter()
+                        .find(|special| cell == special.symbol().to_string())
+                    {
+                        return Ok(Some(Cell::of_special(special)));
+                    }
+                    match cell.parse::<Value>().map_err(|_| ())? {
+                        value if value == Value::default() => Ok(None),
+                        value => Cell::with_value(value, rule).map(Some).ok_or(()),
+                    }
+                })
+                .collect::<Result<Vec<BoardCell>, ()>>()
+        })
+        .collect::<Result<Vec<Vec<BoardCell>>, ()>>()?;
+
+    match rows.iter().all(|row| row.len() == rows[0].len()) {
+        true => Ok((Array2D::from_rows(&rows), walls, voids)),
+        false => Err(()),
+    }
+}
+
+/// Returns the notation of a grid.
+///
+/// # Arguments
+///
+/// * `grid` - the grid to write
+/// * `rules` - the rules deciding the walls of the grid and the coordinates lying off the board
+pub(super) fn write_grid(grid: &BoardGrid, rules: &Rules) -> String {
+    (0..grid.num_rows())
+        .map(|row| {
+            (0..grid.num_columns())
+                .map(|col| match grid.get(row, col).unwrap() {
+                    _ if !rules.contains((row, col)) => VOID.to_string(),
+                    Some(c) => c.to_string(),
+                    None if rules.is_wall((row, col)) => WALL.to_string(),
+                    None => 0.to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(&CELL_SEPARATOR.to_string())
+        })
+        .collect::<Vec<String>>()
+        .join(&ROW_SEPARATOR.to_string())
+}
+
+/// Returns whether the coordinates read as lying off the board are exactly those which lie off a
+/// board played by the specified rules.
+///
+/// # Arguments
+///
+/// * `rules` - the rules deciding which coordinates lie off the board
+/// * `voids` - the coordinates read as lying off the board
+pub(super) fn fits_voids(rules: &Rules, voids: &[BoardCoord]) -> bool {
+    let (rows, cols) = rules.grid_size();
+    (0..rows)
+        .flat_map(|row| (0..cols).map(move |col| (row, col)))
+        .all(|pos| rules.contains(pos) != voids.contains(&pos))
+}
+
+/// Parses a grid from its notation, checking that it fits the rules: that it is of the size they
+/// require, and walled and shaped as they require.
+///
+/// # Arguments
+///
+/// * `s` - the notation of the grid
+/// * `rules` - the rules the grid must fit
+pub(super) fn read_fitting(s: &str, rules: &Rules) -> Result<BoardGrid, ()> {
+    let (grid, mut walls, voids) = read_grid(s, rules.variant.rule())?;
+    let mut expected = rules.walls.clone();
+    expected.sort_unstable();
+    walls.sort_unstable();
+    match (grid.num_rows(), grid.num_columns()) == rules.grid_size()
+        && walls == expected
+        && fits_voids(rules, &voids)
+    {
+        true => Ok(grid),
+        false => Err(()),
+    }
+}
+
+/// Implementation of the `FromStr` trait for `Board`.
+impl std::str::FromStr for Board {
+    type Err = ();
+
+    /// Parses a board (with no score or history) from its notation. The board is played by the
+    /// default rules, other than being sized to fit the grid and walled as written. A grid with
+    /// coordinates lying off the board is taken to be hexagonal, and must lie off the board
+    /// exactly where the hexagon does.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (grid, walls, voids) = read_grid(s, Variant::Classic.rule())?;
+        let rules = Rules {
+            rows: grid.num_rows(),
+            cols: grid.num_columns(),
+            walls,
+            shape: match voids.is_empty() {
+                true => Shape::Square,
+                false => Shape::Hex,
+            },
+            ..Rules::default()
+        };
+        rules.validate()?;
+        if !fits_voids(&rules, &voids) {
+            return Err(());
+        }
+
+        Ok(Board {
+            grid,
+            ..Board::empty(rules)
+        })
+    }
+}
+
+impl Board {
+    /// Returns a new instance of a game board, played by the specified rules, starting from the
+    /// position written in notation rather than from a spawned cell. Cells spawned afterwards are
+    /// entirely determined by the specified seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - the rules the game is played by
+    /// * `notation` - the notation of the starting position, which must fit the rules
+    /// * `seed` - the seed of the random number generator used for spawning cells
+    pub fn with_position(rules: Rules, notation: &str, seed: u64) -> Result<Self, ()> {
+        rules.validate()?;
+        Ok(Board {
+            grid: read_fitting(notation, &rules)?,
+            rng: Pcg64::seed_from_u64(seed),
+            ..Board::empty(rules)
+        })
+    }
+
+    /// Returns the notation of the board's grid.
+    pub fn notation(&self) -> String {
+        write_grid(&self.grid, &self.rules)
+    }
+}
@@ -0,0 +1,308 @@
+use std::collections::BTreeMap;
+
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use super::geometry::{Geometry, Shape};
+use super::{BoardCoord, BOARD_COLS, BOARD_ROWS, HISTORY_SIZE, WIN_VALUE};
+use crate::cell::{Fibonacci, MergeRule, Powers, Special, Triples};
+use crate::{Cell, Direction};
+
+/// Defines the largest number of rows or columns a board may have.
+const MAX_SIDE: usize = 16;
+
+/// Defines the largest number of undos the rules may allow.
+const MAX_UNDOS: usize = 1000;
+
+/// The representation of each variant of the game, deciding the values cells hold and how they
+/// merge.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Variant {
+    /// Cells hold powers of two, and equal cells merge.
+    #[default]
+    Classic,
+    /// Cells hold Fibonacci numbers, and consecutive Fibonacci numbers merge.
+    Fibonacci,
+    /// Cells hold powers of three, and three equal cells in a row merge.
+    Triples,
+}
+
+/// Implementation of the `Display` trait for `Variant`.
+impl std::fmt::Display for Variant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Variant::Classic => "classic",
+                Variant::Fibonacci => "fibonacci",
+                Variant::Triples => "triples",
+            }
+        )
+    }
+}
+
+/// Implementation of the `FromStr` trait for `Variant`.
+impl std::str::FromStr for Variant {
+    type Err = ();
+
+    /// Parses a variant from its lowercase name, as written by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Variant::ALL
+            .into_iter()
+            .find(|variant| variant.to_string() == s)
+            .ok_or(())
+    }
+}
+
+impl Variant {
+    /// Every variant of the game.
+    pub const ALL: [Variant; 3] = [Variant::Classic, Variant::Fibonacci, Variant::Triples];
+
+    /// Returns the merge rule of the variant.
+    pub fn rule(self) -> &'static dyn MergeRule {
+        match self {
+            Variant::Classic => &Powers,
+            Variant::Fibonacci => &Fibonacci,
+            Variant::Triples => &Triples,
+        }
+    }
+
+    /// Returns whether the variant is the classic game.
+    fn is_classic(&self) -> bool {
+        *self == Variant::Classic
+    }
+}
+
+/// The representation of the configurable rules of a game.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Rules {
+    /// The number of rows in the board.
+    pub rows: usize,
+    /// The number of columns in the board, or in each layer of a cube.
+    pub cols: usize,
+    /// The number of layers of a cube, which is one for a flat board.
+    #[serde(skip_serializing_if = "is_flat")]
+    pub layers: usize,
+    /// The cell value that must be reached to win the game.
+    pub win_value: usize,
+    /// The maximum number of undos the player can perform in a row.
+    pub undo_limit: usize,
+    /// The coordinates of the walls, which cells can neither enter nor pass through.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub walls: Vec<BoardCoord>,
+    /// Whether the rows and columns wrap around, so that cells shifted off one edge of the board
+    /// come back on the opposite edge.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub wrap: bool,
+    /// Whether the board can also be shifted diagonally.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub diagonal: bool,
+    /// The shape of the board, deciding which coordinates of the grid hold cells and in which
+    /// directions the board can be shifted.
+    #[serde(skip_serializing_if = "Shape::is_square")]
+    pub shape: Shape,
+    /// The variant of the game, deciding the values cells hold and how they merge.
+    #[serde(skip_serializing_if = "Variant::is_classic")]
+    pub variant: Variant,
+    /// The probability of each kind of special cell being spawned in place of a cell holding a
+    /// value. Kinds which are not listed are never spawned.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub specials: BTreeMap<Special, f64>,
+}
+
+/// Implementation of the `Default` trait for `Rules`.
+impl Default for Rules {
+    /// The rules of the classic game: a four by four board, won upon reaching 2048.
+    fn default() -> Self {
+        Self {
+            rows: BOARD_ROWS,
+            cols: BOARD_COLS,
+            layers: 1,
+            win_value: WIN_VALUE,
+            undo_limit: HISTORY_SIZE,
+            walls: Vec::new(),
+            wrap: false,
+            diagonal: false,
+            shape: Shape::Square,
+            variant: Variant::Classic,
+            specials: BTreeMap::new(),
+        }
+    }
+}
+
+/// Implementation of the `Display` trait for `Rules`.
+impl std::fmt::Display for Rules {
+    /// Writes the rules as e.g. `4x4, win at 2048, 1 undo` (or `4x4x4, ...` for a board of four
+    /// layers), followed by the shape, the variant, whether the board wraps or shifts diagonally,
+    /// the walls and the chance of spawning each special cell, if any.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}x{}{}, win at {}, {} undo{}",
+            self.rows,
+            self.cols,
+            match self.layers {
+                1 => String::new(),
+                layers => format!("x{}", layers),
+            },
+            self.win_value,
+            self.undo_limit,
+            if self.undo_limit == 1 { "" } else { "s" }
+        )?;
+        if !self.shape.is_square() {
+            write!(f, ", {}", self.shape)?;
+        }
+        if !self.variant.is_classic() {
+            write!(f, ", {}", self.variant)?;
+        }
+        if self.wrap {
+            write!(f, ", wrapping")?;
+        }
+        if self.diagonal {
+            write!(f, ", diagonal")?;
+        }
+        if !self.walls.is_empty() {
+            let walls = self
+                .walls
+                .iter()
+                .map(|(row, col)| format!("{}:{}", row, col))
+                .collect::<Vec<String>>();
+            write!(f, ", walls at {}", walls.join(" "))?;
+        }
+        for (special, chance) in &self.specials {
+            write!(f, ", {}s at {:.1}%", special, chance * 100.0)?;
+        }
+        Ok(())
+    }
+}
+
+impl Rules {
+    /// Checks that a game can be played with the rules, i.e. that each side of the board (and its
+    /// number of layers) holds between one and `MAX_SIDE` cells, that the other rules suit the
+    /// shape of the board, that at most `MAX_UNDOS` undos are allowed, that the winning value can
+    /// be held by a cell of the variant, that the walls lie on the board without filling it, that
+    /// the chances of spawning special cells are probabilities which sum to at most one and that a
+    /// board shifted diagonally does not wrap.
+    pub fn validate(&self) -> Result<(), ()> {
+        let mut walls = self.walls.clone();
+        walls.sort_unstable();
+        walls.dedup();
+
+        match (1..=MAX_SIDE).contains(&self.rows)
+            && (1..=MAX_SIDE).contains(&self.cols)
+            && (1..=MAX_SIDE).contains(&self.layers)
+            && self.geometry().fits(self)
+            && self.undo_limit <= MAX_UNDOS
+            && self.variant.rule().is_valid(&self.win_value.into())
+            && walls.len() == self.walls.len()
+            && walls.iter().all(|pos| self.contains(*pos))
+            && walls.len() < self.cells().count()
+            && self
+                .specials
+                .values()
+                .all(|chance| (0.0..=1.0).contains(chance))
+            && self.specials.values().sum::<f64>() <= 1.0
+            && !(self.diagonal && self.wrap)
+        {
+            true => Ok(()),
+            false => Err(()),
+        }
+    }
+
+    /// Sorts the walls, so that rules differing only in the order their walls are listed compare
+    /// (and are stored) as equal.
+    pub fn normalize(&mut self) {
+        self.walls.sort_unstable();
+    }
+
+    /// Returns whether there is a wall at the specified coordinate.
+    ///
+    /// # Arguments
+    ///
+    /// * `pos` - the coordinate to check
+    pub fn is_wall(&self, pos: BoardCoord) -> bool {
+        self.walls.contains(&pos)
+    }
+
+    /// Returns the geometry of the board's shape.
+    pub fn geometry(&self) -> &'static dyn Geometry {
+        self.shape.geometry()
+    }
+
+    /// Returns whether the coordinate of the grid holds a cell of the board (which may be a
+    /// wall), as decided by its shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `pos` - the coordinate to check
+    pub fn contains(&self, pos: BoardCoord) -> bool {
+        self.geometry().contains(self, pos)
+    }
+
+    /// Returns the number of rows and columns of the grid the board is stored in. The layers of
+    /// a cube are stored side by side, so the grid holds the columns of every layer.
+    pub fn grid_size(&self) -> (usize, usize) {
+        (self.rows, self.cols * self.layers)
+    }
+
+    /// Returns the coordinate of every cell of the board (including walls), row by row.
+    pub fn cells(&self) -> impl Iterator<Item = BoardCoord> + '_ {
+        let (rows, cols) = self.grid_size();
+        (0..rows)
+            .flat_map(move |row| (0..cols).map(move |col| (row, col)))
+            .filter(|pos| self.contains(*pos))
+    }
+
+    /// Returns every direction in which the board can be shifted, as decided by its shape and
+    /// whether diagonal shifts are allowed.
+    pub fn directions(&self) -> Vec<Direction> {
+        self.geometry().directions(self)
+    }
+
+    /// Draws the kind of a newly spawned cell, returning `None` if it should hold a value. The
+    /// random number generator is only drawn from if special cells may be spawned, so that games
+    /// without them are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - the source of randomness used to pick the kind
+    pub fn spawn_special(&self, rng: &mut (impl rand::Rng + ?Sized)) -> Option<Special> {
+        if self.specials.is_empty() {
+            return None;
+        }
+        let mut draw = rng.gen::<f64>();
+        for (special, chance) in &self.specials {
+            if draw < *chance {
+                return Some(*special);
+            }
+            draw -= chance;
+        }
+        None
+    }
+
+    /// Draws a newly spawned cell: a special cell by its chance, or else a cell holding a value
+    /// drawn by the merge rule of the variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - the source of randomness used to pick the cell
+    pub fn spawn_cell(&self, rng: &mut dyn RngCore) -> Cell {
+        let rule = self.variant.rule();
+        match self.spawn_special(rng) {
+            Some(special) => Cell::of_special(special),
+            None => Cell::of(rule.spawn(rng), rule).unwrap(),
+        }
+    }
+}
+
+/// Returns whether a board of the specified number of layers is flat, i.e. has a single layer.
+///
+/// # Arguments
+///
+/// * `layers` - the number of layers of the board
+fn is_flat(layers: &usize) -> bool {
+    *layers == 1
+}
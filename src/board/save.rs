@@ -0,0 +1,63 @@
+use super::notation::{read_fitting, write_grid};
+use super::*;
+
+/// The representation of a board as it is serialized, with each grid written in board notation.
+#[derive(Serialize, Deserialize)]
+pub(super) struct Save {
+    rules: Rules,
+    grid: String,
+    score: usize,
+    history: Vec<(String, usize)>,
+    rng: Pcg64,
+}
+
+/// Implementation of the `From` trait for `Save`.
+impl From<Board> for Save {
+    fn from(board: Board) -> Self {
+        Self {
+            grid: write_grid(&board.grid, &board.rules),
+            score: board.score,
+            history: board
+                .history
+                .iter()
+                .map(|(grid, score)| (write_grid(grid, &board.rules), *score))
+                .collect(),
+            rng: board.rng,
+            rules: board.rules,
+        }
+    }
+}
+
+/// Implementation of the `TryFrom` trait for `Board`.
+impl TryFrom<Save> for Board {
+    type Error = String;
+
+    /// Restores a board, checking that every grid fits the rules and is walled and shaped as they
+    /// require, and that the history holds no more grids than the rules allow undos.
+    fn try_from(mut save: Save) -> Result<Self, Self::Error> {
+        save.rules.normalize();
+        save.rules
+            .validate()
+            .map_err(|_| "invalid rules".to_string())?;
+        if save.history.len() > save.rules.undo_limit {
+            return Err("history longer than the undo limit".to_string());
+        }
+
+        let read = |notation: &str| {
+            read_fitting(notation, &save.rules).map_err(|_| format!("invalid grid: {}", notation))
+        };
+
+        Ok(Board {
+            grid: read(&save.grid)?,
+            history: save
+                .history
+                .iter()
+                .map(|(grid, score)| Ok((read(grid)?, *score)))
+                .collect::<Result<Vec<(BoardGrid, usize)>, String>>()?,
+            score: save.score,
+            rng: save.rng,
+            spawner: Box::new(Random),
+            rules: save.rules,
+        })
+    }
+}
@@ -0,0 +1,199 @@
+use rand::seq::SliceRandom;
+use rand::RngCore;
+
+use super::{Board, BoardCoord};
+use crate::Cell;
+
+/// A strategy choosing the cell spawned on a board after each shift, and where it is spawned.
+pub trait Spawner: Send + Sync {
+    /// Returns the cell to spawn on the board and the empty coordinate at which to spawn it, or
+    /// `None` if nothing is to be spawned.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the board on which to spawn
+    /// * `rng` - the source of randomness of the board
+    fn spawn(&mut self, board: &Board, rng: &mut dyn RngCore) -> Option<(BoardCoord, Cell)>;
+
+    /// Returns a copy of the spawner, so that a board can be cloned along with it.
+    fn boxed(&self) -> Box<dyn Spawner>;
+}
+
+/// Implementation of the `Clone` trait for `Box<dyn Spawner>`.
+impl Clone for Box<dyn Spawner> {
+    fn clone(&self) -> Self {
+        self.boxed()
+    }
+}
+
+/// The spawner of the classic game, which spawns a cell drawn by the rules at a random empty
+/// coordinate.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Random;
+
+/// Implementation of the `Spawner` trait for `Random`.
+impl Spawner for Random {
+    fn spawn(&mut self, board: &Board, rng: &mut dyn RngCore) -> Option<(BoardCoord, Cell)> {
+        let empty = board
+            .get_cells_by_emptiness(true)
+            .collect::<Vec<BoardCoord>>();
+        let pos = *empty.choose(rng)?;
+        Some((pos, board.rules.spawn_cell(rng)))
+    }
+
+    fn boxed(&self) -> Box<dyn Spawner> {
+        Box::new(*self)
+    }
+}
+
+/// The worth of a board to the player: the empty cells and then the score they are left with, or
+/// `None` if the board cannot be shifted, which is worse than any other.
+type Worth = Option<(usize, usize)>;
+
+/// The worth greater than that of any board, bounding a search before anything is found.
+const BEST: Worth = Some((usize::MAX, usize::MAX));
+
+/// The spawner of an "evil" game, which spawns whichever cell leaves the player worst off. Every
+/// value the rules spawn is tried at every empty coordinate, and each is judged by a minimax
+/// search: the player replies with their best shift and the spawner with its worst cell, `depth`
+/// shifts deep, before the board is judged by its worth. Special cells are never spawned.
+#[derive(Clone, Copy, Debug)]
+pub struct Adversarial {
+    /// The number of shifts the search looks ahead.
+    pub depth: usize,
+}
+
+impl Adversarial {
+    /// Returns every cell which may be spawned on the board, with its coordinate and the board it
+    /// would leave.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the board on which to spawn
+    fn candidates(board: &Board) -> impl Iterator<Item = (BoardCoord, Cell, Board)> + '_ {
+        let rule = board.rules.variant.rule();
+        board
+            .get_cells_by_emptiness(true)
+            .flat_map(move |pos| rule.spawns().iter().map(move |value| (pos, *value)))
+            .filter_map(move |(pos, value)| {
+                let cell = Cell::of(value, rule)?;
+                let mut next = board.clone();
+                next.place(pos, cell.clone()).ok()?;
+                Some((pos, cell, next))
+            })
+    }
+
+    /// Returns the worth of the board to the player, who shifts it as well as they can, looking
+    /// the specified number of shifts ahead. The search is pruned by alpha-beta: a worth outside
+    /// the bounds is only known to lie outside them.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the board the player is to shift
+    /// * `depth` - the number of shifts to look ahead
+    /// * `alpha` - the worth the player is already assured of
+    /// * `beta` - the worth the spawner already holds the player to
+    fn shift(board: &Board, depth: usize, mut alpha: Worth, beta: Worth) -> Worth {
+        let moves = board.legal_moves();
+        if moves.is_empty() {
+            return None;
+        }
+        if depth == 0 {
+            return Some((board.count_empty(), board.score()));
+        }
+        let mut best = None;
+        for next in moves.into_iter().filter_map(|dir| board.shifted(dir)) {
+            best = best.max(Adversarial::reply(&next, depth - 1, alpha, beta));
+            alpha = alpha.max(best);
+            if alpha >= beta {
+                break;
+            }
+        }
+        best
+    }
+
+    /// Returns the worth of the shifted board to the player once the spawner has spawned the cell
+    /// leaving them worst off, bounded as by `shift`.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the board the spawner is to spawn on
+    /// * `depth` - the number of shifts to look ahead after the spawn
+    /// * `alpha` - the worth the player is already assured of
+    /// * `beta` - the worth the spawner already holds the player to
+    fn reply(board: &Board, depth: usize, alpha: Worth, mut beta: Worth) -> Worth {
+        let mut worst = None;
+        for (_, _, next) in Adversarial::candidates(board) {
+            let worth = Adversarial::shift(&next, depth, alpha, beta);
+            worst = Some(worst.map_or(worth, |worst: Worth| worst.min(worth)));
+            beta = beta.min(worth);
+            if alpha >= beta {
+                break;
+            }
+        }
+        // a full board has nowhere to spawn, and is shifted as it is
+        worst.unwrap_or_else(|| Adversarial::shift(board, depth, alpha, beta))
+    }
+}
+
+/// Implementation of the `Spawner` trait for `Adversarial`.
+impl Spawner for Adversarial {
+    fn spawn(&mut self, board: &Board, _: &mut dyn RngCore) -> Option<(BoardCoord, Cell)> {
+        let mut chosen = None;
+        let mut beta = BEST;
+        for (pos, cell, next) in Adversarial::candidates(board) {
+            let worth = Adversarial::shift(&next, self.depth, None, beta);
+            if chosen.is_none() || worth < beta {
+                chosen = Some((pos, cell));
+                beta = worth;
+            }
+        }
+        chosen
+    }
+
+    fn boxed(&self) -> Box<dyn Spawner> {
+        Box::new(*self)
+    }
+}
+
+/// A spawner following a script, as in a puzzle. Each cell is spawned at its coordinate or, if
+/// none is given or it is occupied, at the first empty cell of the board, row by row. A cell with
+/// nowhere to spawn is lost, and nothing is spawned once the script runs out.
+#[derive(Clone, Debug)]
+pub struct Scripted {
+    /// The cells to spawn, in order, with the coordinates at which to spawn them.
+    spawns: Vec<(Cell, Option<BoardCoord>)>,
+    /// The number of cells of the script spawned (or lost).
+    next: usize,
+}
+
+impl Scripted {
+    /// Returns a spawner following the specified script.
+    ///
+    /// # Arguments
+    ///
+    /// * `spawns` - the cells to spawn, in order, with the coordinates at which to spawn them
+    pub fn new(spawns: Vec<(Cell, Option<BoardCoord>)>) -> Self {
+        Self { spawns, next: 0 }
+    }
+}
+
+/// Implementation of the `Spawner` trait for `Scripted`.
+impl Spawner for Scripted {
+    fn spawn(&mut self, board: &Board, _: &mut dyn RngCore) -> Option<(BoardCoord, Cell)> {
+        let (cell, at) = self.spawns.get(self.next)?.clone();
+        self.next += 1;
+
+        let empty = board
+            .get_cells_by_emptiness(true)
+            .collect::<Vec<BoardCoord>>();
+        let pos = at
+            .filter(|pos| empty.contains(pos))
+            .or_else(|| board.rules.cells().find(|pos| empty.contains(pos)))?;
+        Some((pos, cell))
+    }
+
+    fn boxed(&self) -> Box<dyn Spawner> {
+        Box::new(self.clone())
+    }
+}
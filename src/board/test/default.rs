@@ -9,11 +9,11 @@ fn grid() {
         .all(|cell| cell.is_none()));
 }
 
-/// Affirm that the default board is instantiated with empty history, containing the maximum
-/// capacity specified by `HISTORY_SIZE`.
+/// Affirm that the default board is instantiated with empty history, allowing the number of undos
+/// specified by `HISTORY_SIZE`.
 #[test]
 fn history() {
     let board = Board::default();
-    assert_eq!(HISTORY_SIZE, board.history.capacity());
+    assert_eq!(HISTORY_SIZE, board.rules().undo_limit);
     assert!(board.history.is_empty());
 }
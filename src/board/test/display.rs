@@ -0,0 +1,35 @@
+use super::*;
+
+/// Affirm that a square board is written row by row, with its cells separated by spaces.
+#[test]
+fn square() {
+    let board = "2,#/0,16".parse::<Board>().unwrap();
+    assert_eq!("2 #\n0 16", board.to_string());
+}
+
+/// Affirm that a hexagonal board is written with its cells padded to a common width and its rows
+/// indented by half a cell for each row they lie away from the middle row.
+#[test]
+fn hex() {
+    let board = ".,0,2/0,16,0/0,0,.".parse::<Board>().unwrap();
+    assert_eq!("    0   2\n  0  16   0\n    0   0", board.to_string());
+}
+
+/// Affirm that a cube is written as its layers side by side, with its cells padded to a common
+/// width.
+#[test]
+fn cube() {
+    let rules = Rules {
+        rows: 2,
+        cols: 2,
+        layers: 2,
+        shape: Shape::Cube,
+        ..Rules::default()
+    };
+    let (grid, _, _) = crate::board::notation::read_grid("2,0,16,0/0,0,0,4", &Powers).unwrap();
+    let board = Board {
+        grid,
+        ..Board::empty(rules)
+    };
+    assert_eq!(" 2  0 | 16  0\n 0  0 |  0  4", board.to_string());
+}
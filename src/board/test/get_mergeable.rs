@@ -4,32 +4,32 @@ use super::*;
 #[test]
 fn input_0_0_0_0() {
     let cells = vec![None, None, None, None];
-    assert!(Board::get_mergeable(&cells).is_empty());
+    assert!(Board::get_mergeable(&cells, &Powers).is_empty());
 }
 
 /// Affirm that `Board::get_mergeable` reports no mergeable pairs for a singular non-empty cell.
 #[test]
 fn input_0_0_0_2() {
     let cells = vec![None, None, None, Some(Cell::new(2))];
-    assert!(Board::get_mergeable(&cells).is_empty());
+    assert!(Board::get_mergeable(&cells, &Powers).is_empty());
 }
 
 /// Affirm that `Board::get_mergeable` reports one mergeable pair for a contiguous pair of
 /// same-valued cells.
 #[test]
 fn input_0_0_2_2() {
-    let expect = vec![(2, 3)];
+    let expect = vec![vec![2, 3]];
     let cells = vec![None, None, Some(Cell::new(2)), Some(Cell::new(2))];
-    assert_eq!(expect, Board::get_mergeable(&cells));
+    assert_eq!(expect, Board::get_mergeable(&cells, &Powers));
 }
 
 /// Affirm that `Board::get_mergeable` reports one mergeable pair for a pair of same-valued cells
 /// separated by an empty cell.
 #[test]
 fn input_0_2_0_2() {
-    let expect = vec![(1, 3)];
+    let expect = vec![vec![1, 3]];
     let cells = vec![None, Some(Cell::new(2)), None, Some(Cell::new(2))];
-    assert_eq!(expect, Board::get_mergeable(&cells));
+    assert_eq!(expect, Board::get_mergeable(&cells, &Powers));
 }
 
 /// Affirm that `Board::get_mergeable` reports no mergeable pairs for a pair of same-valued cells
@@ -42,29 +42,44 @@ fn input_0_2_4_2() {
         Some(Cell::new(4)),
         Some(Cell::new(2)),
     ];
-    assert!(Board::get_mergeable(&cells).is_empty());
+    assert!(Board::get_mergeable(&cells, &Powers).is_empty());
 }
 
 #[test]
 fn input_2_2_2_2() {
-    let expect = vec![(0, 1), (2, 3)];
+    let expect = vec![vec![0, 1], vec![2, 3]];
     let cells = vec![
         Some(Cell::new(2)),
         Some(Cell::new(2)),
         Some(Cell::new(2)),
         Some(Cell::new(2)),
     ];
-    assert_eq!(expect, Board::get_mergeable(&cells));
+    assert_eq!(expect, Board::get_mergeable(&cells, &Powers));
 }
 
 #[test]
 fn input_2_4_4_2() {
-    let expect = vec![(1, 2)];
+    let expect = vec![vec![1, 2]];
     let cells = vec![
         Some(Cell::new(2)),
         Some(Cell::new(4)),
         Some(Cell::new(4)),
         Some(Cell::new(2)),
     ];
-    assert_eq!(expect, Board::get_mergeable(&cells));
+    assert_eq!(expect, Board::get_mergeable(&cells, &Powers));
+}
+
+/// Affirm that `Board::get_mergeable` reports runs of three equal cells, skipping empty cells,
+/// when the merge rule merges three cells in a row.
+#[test]
+fn triples() {
+    let cell = |value| Some(Cell::of(value, &Triples).unwrap());
+    let cells = vec![cell(3), cell(3), None, cell(3), cell(3), cell(3), cell(9)];
+    assert_eq!(vec![vec![0, 1, 3]], Board::get_mergeable(&cells, &Triples));
+
+    let cells = vec![cell(9), cell(3), cell(3), cell(9), cell(9), None, cell(9)];
+    assert_eq!(vec![vec![3, 4, 6]], Board::get_mergeable(&cells, &Triples));
+
+    let cells = vec![cell(3), cell(3), cell(9), cell(3)];
+    assert!(Board::get_mergeable(&cells, &Triples).is_empty());
 }
@@ -1,9 +1,19 @@
 use super::*;
+use crate::cell::{saturate, Fibonacci, Powers, Triples};
 
 mod default;
+mod display;
 mod get_cells_by_emptiness;
 mod get_mergeable;
+mod movement;
 mod new;
+mod notation;
+mod plan_group;
+mod save;
 mod shift_group;
 mod spawn;
 mod spawn_at;
+mod spawner;
+mod status;
+mod with_position;
+mod with_seed;
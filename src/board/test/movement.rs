@@ -0,0 +1,426 @@
+use super::*;
+
+/// Affirm that shifting merges cells, adds their value to the score and spawns a new cell.
+#[test]
+fn shift_merges_and_spawns() {
+    let mut board = "2,2,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().unwrap();
+    let events = board.movement(Move::Shift(Direction::Left)).unwrap();
+
+    assert_eq!(4, board.score());
+    assert_eq!(Some(&Some(Cell::new(4))), board.grid.get(0, 0));
+    assert_eq!(BOARD_ROWS * BOARD_COLS - 2, board.count_empty());
+    match events.as_slice() {
+        [Event::Merge {
+            from: (0, 1),
+            into: (0, 0),
+            cell: merged,
+        }, Event::Spawn { at, cell }] => {
+            assert_eq!(Cell::new(4), *merged);
+            assert_eq!(Some(&Some(cell.clone())), board.grid.get(at.0, at.1))
+        }
+        _ => panic!("expected a merge followed by a spawn"),
+    }
+}
+
+/// Affirm that shifting in a direction which changes nothing fails and spawns nothing.
+#[test]
+fn shift_invalid() {
+    let mut board = "2,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().unwrap();
+    board.movement(Move::Shift(Direction::Up)).unwrap_err();
+    assert_eq!("2,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0", board.notation());
+    assert!(board.history.is_empty());
+}
+
+/// Affirm that every row is shifted when moving right.
+#[test]
+fn shift_right() {
+    let mut board = "2,2,0,0/0,4,0,4/0,0,0,0/8,0,0,0".parse::<Board>().unwrap();
+    board.movement(Move::Shift(Direction::Right)).unwrap();
+    assert_eq!(Some(&Some(Cell::new(4))), board.grid.get(0, 3));
+    assert_eq!(Some(&Some(Cell::new(8))), board.grid.get(1, 3));
+    assert_eq!(Some(&Some(Cell::new(8))), board.grid.get(3, 3));
+    assert_eq!(12, board.score());
+}
+
+/// Affirm that every column is shifted when moving down.
+#[test]
+fn shift_down() {
+    let mut board = "2,0,0,0/2,0,0,4/0,0,0,0/0,0,0,4".parse::<Board>().unwrap();
+    board.movement(Move::Shift(Direction::Down)).unwrap();
+    assert_eq!(Some(&Some(Cell::new(4))), board.grid.get(3, 0));
+    assert_eq!(Some(&Some(Cell::new(8))), board.grid.get(3, 3));
+}
+
+/// Affirm that undoing restores the grid and score from before the last shift.
+#[test]
+fn undo() {
+    let notation = "2,2,0,0/0,0,0,0/0,0,0,0/0,0,0,0";
+    let mut board = notation.parse::<Board>().unwrap();
+    board.movement(Move::Shift(Direction::Left)).unwrap();
+    board.movement(Move::Undo).unwrap();
+
+    assert_eq!(notation, board.notation());
+    assert_eq!(0, board.score());
+}
+
+/// Affirm that no more than `HISTORY_SIZE` shifts can be undone.
+#[test]
+fn undo_exhausted() {
+    let mut board = "2,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().unwrap();
+    board.movement(Move::Shift(Direction::Right)).unwrap();
+    board.movement(Move::Shift(Direction::Left)).unwrap();
+    for _ in 0..HISTORY_SIZE {
+        board.movement(Move::Undo).unwrap();
+    }
+    board.movement(Move::Undo).unwrap_err();
+}
+
+/// Affirm that walls split rows and columns into segments which are shifted independently, so
+/// that cells neither pass through nor merge across a wall.
+#[test]
+fn shift_walls() {
+    let board = "0,2,#,2/2,2,#,0/#,0,0,0/4,4,4,#".parse::<Board>().unwrap();
+    assert_eq!(
+        "2,0,#,2/4,0,#,0/#,0,0,0/8,4,0,#",
+        board.shifted(Direction::Left).unwrap().notation()
+    );
+    assert_eq!(
+        "0,2,#,2/0,4,#,0/#,0,0,0/0,4,8,#",
+        board.shifted(Direction::Right).unwrap().notation()
+    );
+    assert_eq!(
+        "2,4,#,2/0,4,#,0/#,0,4,0/4,0,0,#",
+        board.shifted(Direction::Up).unwrap().notation()
+    );
+    assert_eq!(
+        "0,0,#,0/2,0,#,0/#,4,0,2/4,4,4,#",
+        board.shifted(Direction::Down).unwrap().notation()
+    );
+}
+
+/// Returns a wrapping board parsed from notation.
+fn torus(notation: &str) -> Board {
+    let mut board = notation.parse::<Board>().unwrap();
+    board.rules.wrap = true;
+    board
+}
+
+/// Affirm that on a wrapping board, a cell is pushed over the edge to merge with the cell on the
+/// opposite edge, while groups with nothing to merge over the edge shift as usual.
+#[test]
+fn shift_wrap() {
+    let board = torus("0,2,4,2/2,0,0,2/0,2,0,0/2,4,8,2");
+    assert_eq!(
+        "4,0,0,4/4,0,0,0/2,0,0,0/4,8,0,4",
+        board.shifted(Direction::Left).unwrap().notation()
+    );
+    assert_eq!(
+        "4,4,0,0/0,0,0,4/0,0,0,2/4,0,4,8",
+        board.shifted(Direction::Right).unwrap().notation()
+    );
+}
+
+/// Affirm that on a wrapping board, columns wrap as rows do, and a wall keeps the segment
+/// spanning the edges of the board whole.
+#[test]
+fn shift_wrap_columns_and_walls() {
+    let board = torus("2,#/0,2/0,0/2,2");
+    assert_eq!(
+        "4,#/0,4/0,0/0,0",
+        board.shifted(Direction::Up).unwrap().notation()
+    );
+    // the second column is read as rows 2, 3 and 0, so its cell passes over the bottom edge
+    let board = torus("0,0/0,#/0,0/0,2");
+    assert_eq!(
+        "0,2/0,#/0,0/0,0",
+        board.shifted(Direction::Down).unwrap().notation()
+    );
+}
+
+/// Affirm that on a wrapping board, a full board whose only merge is over an edge is not lost.
+#[test]
+fn wrap_game_over() {
+    let mut board = torus("2,4,8,2/4,8,2,4/8,2,4,8/16,32,64,128");
+    assert_eq!(vec![Direction::Left, Direction::Right], board.legal_moves());
+    board.rules.wrap = false;
+    assert_eq!(Status::Lost, board.status());
+}
+
+/// Affirm that in the Fibonacci variant, consecutive Fibonacci numbers merge and score their sum.
+#[test]
+fn shift_fibonacci() {
+    let rules = Rules {
+        variant: Variant::Fibonacci,
+        win_value: 2584,
+        ..Rules::default()
+    };
+    let (grid, _, _) =
+        crate::board::notation::read_grid("1,1,2,3/2,2,0,0/0,3,0,5/0,0,0,0", &Fibonacci).unwrap();
+    let mut board = Board {
+        grid,
+        ..Board::empty(rules)
+    };
+
+    let events = board.movement(Move::Shift(Direction::Left)).unwrap();
+    assert_eq!(10, board.count_empty());
+    assert_eq!(
+        Some(&Some(Cell::of(2, &Fibonacci).unwrap())),
+        board.grid.get(0, 0)
+    );
+    assert_eq!(
+        Some(&Some(Cell::of(5, &Fibonacci).unwrap())),
+        board.grid.get(0, 1)
+    );
+    assert_eq!(
+        Some(&Some(Cell::of(2, &Fibonacci).unwrap())),
+        board.grid.get(1, 1)
+    );
+    assert_eq!(
+        Some(&Some(Cell::of(8, &Fibonacci).unwrap())),
+        board.grid.get(2, 0)
+    );
+    assert_eq!(2 + 5 + 8, board.score());
+    match events.last() {
+        Some(Event::Spawn { cell, .. }) => assert!([1, 2].contains(&saturate(cell.value()))),
+        _ => panic!("expected a spawn"),
+    }
+}
+
+/// Affirm that in the triples variant, three equal cells in a row merge and score once.
+#[test]
+fn shift_triples() {
+    let rules = Rules {
+        variant: Variant::Triples,
+        win_value: 2187,
+        ..Rules::default()
+    };
+    let (grid, _, _) =
+        crate::board::notation::read_grid("3,0,3,3/9,9,3,9/0,0,0,0/27,27,27,27", &Triples).unwrap();
+    let mut board = Board {
+        grid,
+        ..Board::empty(rules)
+    };
+
+    let events = board.movement(Move::Shift(Direction::Right)).unwrap();
+    assert_eq!(
+        Some(&Some(Cell::of(9, &Triples).unwrap())),
+        board.grid.get(0, 3)
+    );
+    assert_eq!(
+        Some(&Some(Cell::of(9, &Triples).unwrap())),
+        board.grid.get(1, 0)
+    );
+    assert_eq!(
+        Some(&Some(Cell::of(27, &Triples).unwrap())),
+        board.grid.get(3, 2)
+    );
+    assert_eq!(
+        Some(&Some(Cell::of(81, &Triples).unwrap())),
+        board.grid.get(3, 3)
+    );
+    assert_eq!(9 + 81, board.score());
+
+    let merges = events
+        .iter()
+        .filter(|event| matches!(event, Event::Merge { .. }))
+        .count();
+    assert_eq!(4, merges);
+}
+
+/// Affirm that on a wrapping board, the first cell of a row can complete a run of three with the
+/// last cells of the row.
+#[test]
+fn shift_triples_wrap() {
+    let rules = Rules {
+        variant: Variant::Triples,
+        win_value: 2187,
+        wrap: true,
+        ..Rules::default()
+    };
+    let (grid, _, _) =
+        crate::board::notation::read_grid("3,9,3,3/0,0,0,0/0,0,0,0/0,0,0,0", &Triples).unwrap();
+    let mut board = Board {
+        grid,
+        ..Board::empty(rules)
+    };
+
+    board.movement(Move::Shift(Direction::Left)).unwrap();
+    assert_eq!(
+        Some(&Some(Cell::of(9, &Triples).unwrap())),
+        board.grid.get(0, 2)
+    );
+    assert_eq!(
+        Some(&Some(Cell::of(9, &Triples).unwrap())),
+        board.grid.get(0, 3)
+    );
+    assert_eq!(9, board.score());
+}
+
+/// Affirm that special cells merge by the rules of their kinds: a bomb clears both cells, freeing
+/// its destination for the cells behind, a joker forms what the other cell would form by merging
+/// and a doubler doubles the other cell.
+#[test]
+fn shift_specials() {
+    let mut board = "B,2,4,0/J,8,8,0/2,D,0,0/0,0,0,0".parse::<Board>().unwrap();
+
+    let events = board.movement(Move::Shift(Direction::Left)).unwrap();
+    assert_eq!(Some(&Some(Cell::new(4))), board.grid.get(0, 0));
+    assert_eq!(Some(&Some(Cell::new(16))), board.grid.get(1, 0));
+    assert_eq!(Some(&Some(Cell::new(8))), board.grid.get(1, 1));
+    assert_eq!(Some(&Some(Cell::new(4))), board.grid.get(2, 0));
+    assert_eq!(16 + 4, board.score());
+    assert_eq!(
+        events[..2],
+        [
+            Event::Clear {
+                from: (0, 1),
+                into: (0, 0)
+            },
+            Event::Slide {
+                from: (0, 2),
+                to: (0, 0)
+            }
+        ]
+    );
+}
+
+/// Affirm that cells merge past the largest `usize` without limit, whereas the score and the
+/// largest value saturate at `usize::MAX` rather than overflowing.
+#[test]
+fn shift_unbounded() {
+    let msb = 1usize << (usize::BITS - 1);
+    let mut board = format!("{},{}/0,0", msb, msb).parse::<Board>().unwrap();
+
+    board.movement(Move::Shift(Direction::Left)).unwrap();
+    let merged = Value::from(msb) * 2u32;
+    assert_eq!(
+        Some(&Cell::with_value(merged.clone(), &Powers)),
+        board.grid.get(0, 0)
+    );
+    assert!(board.notation().starts_with(&format!("{},", merged)));
+    assert_eq!(usize::MAX, board.score());
+    assert_eq!(usize::MAX, board.max_value());
+
+    let mut board = "4,4/0,0".parse::<Board>().unwrap();
+    board.score = usize::MAX - 2;
+    board.movement(Move::Shift(Direction::Left)).unwrap();
+    assert_eq!(usize::MAX, board.score());
+}
+
+/// Returns a board parsed from notation which can also be shifted diagonally.
+fn diagonal(notation: &str) -> Board {
+    let mut board = notation.parse::<Board>().unwrap();
+    board.rules.diagonal = true;
+    board
+}
+
+/// Affirm that diagonal shifts move cells along the diagonals of the board, whatever their
+/// length.
+#[test]
+fn shift_diagonal() {
+    let board = diagonal("2,0,0/0,2,0/4,0,4");
+    let shifted = board.shifted(Direction::UpLeft).unwrap();
+    assert_eq!("4,0,0/0,4,0/4,0,0", shifted.notation());
+    assert_eq!(4, shifted.score());
+    assert_eq!(
+        "0,0,0/0,4,0/4,0,4",
+        board.shifted(Direction::DownRight).unwrap().notation()
+    );
+
+    let board = diagonal("0,0,0/4,0,0/2,0,0");
+    assert_eq!(
+        "0,4,2/0,0,0/0,0,0",
+        board.shifted(Direction::UpRight).unwrap().notation()
+    );
+    assert_eq!(
+        "0,0,0/0,0,0/2,0,0",
+        diagonal("0,0,2/0,0,0/0,0,0")
+            .shifted(Direction::DownLeft)
+            .unwrap()
+            .notation()
+    );
+}
+
+/// Affirm that diagonal shifts are only legal if the rules allow them, and are then considered
+/// when deciding whether the game is over.
+#[test]
+fn diagonal_game_over() {
+    let board = "2,4/8,2".parse::<Board>().unwrap();
+    assert_eq!(Status::Lost, board.status());
+    assert!(board.shifted(Direction::UpLeft).is_none());
+
+    let board = diagonal("2,4/8,2");
+    assert_eq!(
+        vec![Direction::UpLeft, Direction::DownRight],
+        board.legal_moves()
+    );
+    assert_eq!(Status::Playing, board.status());
+}
+
+/// Affirm that a hexagonal board is shifted along its rows, its columns and the lines along which
+/// `row + col` is constant, and cannot be shifted up or down.
+#[test]
+fn shift_hex() {
+    let board = ".,.,2,2,0/.,0,0,0,0/0,0,0,0,0/0,0,0,0,./0,0,0,.,."
+        .parse::<Board>()
+        .unwrap();
+    let shifted = board.shifted(Direction::Left).unwrap();
+    assert_eq!(
+        ".,.,4,0,0/.,0,0,0,0/0,0,0,0,0/0,0,0,0,./0,0,0,.,.",
+        shifted.notation()
+    );
+    assert_eq!(4, shifted.score());
+    assert_eq!(
+        ".,.,0,0,0/.,0,0,0,0/0,0,0,0,0/0,0,0,2,./0,0,2,.,.",
+        board.shifted(Direction::DownRight).unwrap().notation()
+    );
+    assert_eq!(
+        ".,.,0,0,0/.,0,0,0,0/2,0,0,0,0/2,0,0,0,./0,0,0,.,.",
+        board.shifted(Direction::DownLeft).unwrap().notation()
+    );
+    assert!(board.shifted(Direction::Up).is_none());
+    assert!(board.shifted(Direction::Down).is_none());
+}
+
+/// Returns a cube of two rows, two columns and two layers, whose grid is read from notation.
+fn cube(notation: &str) -> Board {
+    let rules = Rules {
+        rows: 2,
+        cols: 2,
+        layers: 2,
+        shape: Shape::Cube,
+        ..Rules::default()
+    };
+    let (grid, _, _) = crate::board::notation::read_grid(notation, &Powers).unwrap();
+    Board {
+        grid,
+        ..Board::empty(rules)
+    }
+}
+
+/// Affirm that a cube is shifted through its layers, and along the rows and columns of each
+/// layer without crossing into the next.
+#[test]
+fn shift_cube() {
+    let board = cube("2,0,2,0/0,0,0,4");
+    let shifted = board.shifted(Direction::Out).unwrap();
+    assert_eq!("4,0,0,0/0,4,0,0", shifted.notation());
+    assert_eq!(4, shifted.score());
+    assert_eq!(
+        "0,0,4,0/0,0,0,4",
+        board.shifted(Direction::In).unwrap().notation()
+    );
+    assert_eq!(
+        "2,0,2,0/0,0,4,0",
+        board.shifted(Direction::Left).unwrap().notation()
+    );
+    assert!(board.shifted(Direction::UpLeft).is_none());
+}
+
+/// Affirm that a full cube whose only merges run through its layers is not lost.
+#[test]
+fn cube_game_over() {
+    let board = cube("2,4,2,8/4,8,16,32");
+    assert_eq!(vec![Direction::In, Direction::Out], board.legal_moves());
+    assert_eq!(Status::Playing, board.status());
+}
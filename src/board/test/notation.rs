@@ -0,0 +1,80 @@
+use super::*;
+
+/// Affirm that a board is written in notation and parsed back unchanged.
+#[test]
+fn round_trip() {
+    let notation = "2,0,0,4/0,8,0,0/0,0,1024,0/0,0,0,2";
+    assert_eq!(notation, notation.parse::<Board>().unwrap().notation());
+}
+
+/// Affirm that the notation of the default board holds only empty cells.
+#[test]
+fn empty() {
+    assert_eq!(
+        "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0",
+        Board::default().notation()
+    );
+}
+
+/// Affirm that the size of the board is taken from the notation.
+#[test]
+fn sized() {
+    let board = "0,0,0/0,2,0".parse::<Board>().unwrap();
+    assert_eq!(2, board.rules().rows);
+    assert_eq!(3, board.rules().cols);
+    assert_eq!("0,0,0/0,2,0", board.notation());
+}
+
+/// Affirm that notation whose rows hold differing numbers of cells is rejected.
+#[test]
+fn ragged() {
+    assert!("0,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().is_err());
+    assert!("".parse::<Board>().is_err());
+}
+
+/// Affirm that notation holding values which are not powers of two is rejected.
+#[test]
+fn invalid_value() {
+    assert!("3,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().is_err());
+    assert!("1,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().is_err());
+    assert!("x,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().is_err());
+}
+
+/// Affirm that walls are written as `#` and become the walls of the board's rules.
+#[test]
+fn walls() {
+    let board = "2,#,0/0,0,#".parse::<Board>().unwrap();
+    assert_eq!(vec![(0, 1), (1, 2)], board.rules().walls);
+    assert_eq!("2,#,0/0,0,#", board.notation());
+    assert!("#,#/#,#".parse::<Board>().is_err());
+}
+
+/// Affirm that special cells are written as the symbols of their kinds.
+#[test]
+fn specials() {
+    let board = "J,2/B,D".parse::<Board>().unwrap();
+    assert_eq!(
+        Some(&Some(Cell::of_special(Special::Bomb))),
+        board.grid.get(1, 0)
+    );
+    assert_eq!("J,2/B,D", board.notation());
+}
+
+/// Affirm that values too large for a `usize` are parsed and written exactly.
+#[test]
+fn unbounded() {
+    let notation = "0,340282366920938463463374607431768211456/2,0";
+    assert_eq!(notation, notation.parse::<Board>().unwrap().notation());
+}
+
+/// Affirm that coordinates lying off the board are written as `.`, making the board hexagonal,
+/// and that they must lie exactly where the corners of the hexagon do.
+#[test]
+fn hex() {
+    let board = ".,0,2/0,4,0/0,0,.".parse::<Board>().unwrap();
+    assert_eq!(Shape::Hex, board.rules().shape);
+    assert_eq!(".,0,2/0,4,0/0,0,.", board.notation());
+    assert!("0,0,./0,0,0/.,0,0".parse::<Board>().is_err());
+    assert!(".,0/0,0".parse::<Board>().is_err());
+    assert!(".,0,0/0,0,0/0,0,0".parse::<Board>().is_err());
+}
@@ -0,0 +1,66 @@
+use super::*;
+
+/// Returns the travels of a group as `(from, to, merged)` tuples.
+fn plan(cells: &[BoardCell], dir: Direction) -> Vec<(usize, usize, bool)> {
+    Board::plan_group(cells, dir, &Powers)
+        .into_iter()
+        .map(|travel| (travel.from, travel.to, travel.merged))
+        .collect()
+}
+
+/// Affirm that `Board::plan_group` omits cells which would not move.
+#[test]
+fn unmoved() {
+    let row = vec![Some(Cell::new(2)), Some(Cell::new(4)), None, None];
+    assert!(plan(&row, Direction::Left).is_empty());
+}
+
+/// Affirm that `Board::plan_group` tracks slides and merges towards the start of the group.
+#[test]
+fn input_0_2_2_4_left() {
+    let row = vec![
+        None,
+        Some(Cell::new(2)),
+        Some(Cell::new(2)),
+        Some(Cell::new(4)),
+    ];
+    assert_eq!(
+        vec![(1, 0, false), (2, 0, true), (3, 1, false)],
+        plan(&row, Direction::Left)
+    );
+}
+
+/// Affirm that `Board::plan_group` tracks slides and merges towards the end of the group.
+#[test]
+fn input_2_2_2_0_right() {
+    let row = vec![
+        Some(Cell::new(2)),
+        Some(Cell::new(2)),
+        Some(Cell::new(2)),
+        None,
+    ];
+    assert_eq!(
+        vec![(2, 3, false), (1, 3, true), (0, 2, false)],
+        plan(&row, Direction::Right)
+    );
+}
+
+/// Affirm that `Board::plan_group` frees the destination of cells cleared by a bomb for the cells
+/// behind them.
+#[test]
+fn input_2_b_4_4_left() {
+    let row = vec![
+        Some(Cell::new(2)),
+        Some(Cell::of_special(Special::Bomb)),
+        Some(Cell::new(4)),
+        Some(Cell::new(4)),
+    ];
+    assert_eq!(
+        vec![(1, 0, true), (2, 0, false), (3, 0, true)],
+        plan(&row, Direction::Left)
+    );
+    assert_eq!(
+        Some(vec![Some(Cell::new(8)), None, None, None]),
+        Board::shift_group(row, Direction::Left, &Powers)
+    );
+}
@@ -0,0 +1,113 @@
+use super::*;
+
+/// Affirm that a board restored from its serialized form continues exactly as the original.
+#[test]
+fn round_trip() {
+    let rules = Rules {
+        rows: 3,
+        cols: 5,
+        undo_limit: 2,
+        ..Rules::default()
+    };
+    let mut board = Board::with_rules(rules, 11);
+    for dir in [Direction::Left, Direction::Up, Direction::Right] {
+        let _ = board.movement(Move::Shift(dir));
+    }
+
+    let mut restored =
+        serde_json::from_str::<Board>(&serde_json::to_string(&board).unwrap()).unwrap();
+    assert_eq!(board.rules(), restored.rules());
+    assert_eq!(board.notation(), restored.notation());
+    assert_eq!(board.score(), restored.score());
+
+    for mov in [
+        Move::Undo,
+        Move::Shift(Direction::Down),
+        Move::Shift(Direction::Left),
+    ] {
+        assert_eq!(board.movement(mov), restored.movement(mov));
+        assert_eq!(board.notation(), restored.notation());
+    }
+}
+
+/// Affirm that a serialized board whose grid does not fit its rules is rejected.
+#[test]
+fn mismatched_size() {
+    let json = serde_json::to_string(&Board::with_seed(1))
+        .unwrap()
+        .replace("\"rows\":4", "\"rows\":3");
+    assert!(serde_json::from_str::<Board>(&json).is_err());
+}
+
+/// Affirm that walls are restored, and that a grid walled differently from its rules is rejected.
+#[test]
+fn walls() {
+    let rules = Rules {
+        walls: vec![(1, 1), (2, 3)],
+        ..Rules::default()
+    };
+    let board = Board::with_rules(rules, 4);
+    let json = serde_json::to_string(&board).unwrap();
+
+    let restored = serde_json::from_str::<Board>(&json).unwrap();
+    assert_eq!(board.rules(), restored.rules());
+    assert_eq!(board.notation(), restored.notation());
+    assert!(board.notation().contains('#'));
+
+    let moved = json.replace("[2,3]", "[3,2]");
+    assert!(serde_json::from_str::<Board>(&moved).is_err());
+}
+
+/// Affirm that walls are kept in order, however they were listed when the board was created or
+/// saved.
+#[test]
+fn walls_in_order() {
+    let rules = Rules {
+        walls: vec![(2, 3), (1, 1)],
+        ..Rules::default()
+    };
+    let board = Board::with_rules(rules, 4);
+    assert_eq!(vec![(1, 1), (2, 3)], board.rules().walls);
+
+    let json = serde_json::to_string(&board)
+        .unwrap()
+        .replace("[[1,1],[2,3]]", "[[2,3],[1,1]]");
+    assert!(json.contains("[[2,3],[1,1]]"));
+    let restored = serde_json::from_str::<Board>(&json).unwrap();
+    assert_eq!(board.rules(), restored.rules());
+}
+
+/// Affirm that a cube is restored with its layers, and continues exactly as the original.
+#[test]
+fn cube() {
+    let rules = Rules {
+        layers: 3,
+        shape: Shape::Cube,
+        ..Rules::default()
+    };
+    let mut board = Board::with_rules(rules, 9);
+    let _ = board.movement(Move::Shift(Direction::In));
+    let json = serde_json::to_string(&board).unwrap();
+    assert!(json.contains("\"layers\":3"));
+
+    let mut restored = serde_json::from_str::<Board>(&json).unwrap();
+    assert_eq!(board.rules(), restored.rules());
+    assert_eq!(board.notation(), restored.notation());
+    assert_eq!(
+        board.movement(Move::Shift(Direction::Out)),
+        restored.movement(Move::Shift(Direction::Out))
+    );
+}
+
+/// Affirm that a serialized board holding more history than its rules allow undos is rejected.
+#[test]
+fn history_too_long() {
+    let mut board = Board::with_seed(5);
+    let _ = board.movement(Move::Shift(Direction::Left));
+    let _ = board.movement(Move::Shift(Direction::Right));
+    let json = serde_json::to_string(&board).unwrap();
+    assert!(serde_json::from_str::<Board>(&json).is_ok());
+
+    let json = json.replace("\"undo_limit\":1", "\"undo_limit\":0");
+    assert!(serde_json::from_str::<Board>(&json).is_err());
+}
@@ -3,8 +3,8 @@ use super::*;
 /// Affirm that `Board::shift_group` will return nothing on a group of empty cells.
 #[test]
 fn input_0_0_0_0() {
-    let mut row: Vec<BoardCell> = vec![None, None, None, None];
-    let result = Board::shift_group(row, Direction::Left);
+    let row: Vec<BoardCell> = vec![None, None, None, None];
+    let result = Board::shift_group(row, Direction::Left, &Powers);
     assert!(result.is_none());
 }
 
@@ -13,7 +13,7 @@ fn input_0_0_0_0() {
 #[test]
 fn input_2_0_0_0() {
     let row = vec![Some(Cell::new(2)), None, None, None];
-    let result = Board::shift_group(row, Direction::Left);
+    let result = Board::shift_group(row, Direction::Left, &Powers);
     assert!(result.is_none());
 }
 
@@ -21,8 +21,8 @@ fn input_2_0_0_0() {
 /// the end to the start.
 #[test]
 fn input_0_0_0_2() {
-    let mut row = vec![None, None, None, Some(Cell::new(2))];
-    let result = Board::shift_group(row, Direction::Left).unwrap();
+    let row = vec![None, None, None, Some(Cell::new(2))];
+    let result = Board::shift_group(row, Direction::Left, &Powers).unwrap();
     let mut iter = result.iter();
     assert_eq!(Some(Cell::new(2)), *iter.next().unwrap());
     assert!(iter.all(|cell| cell.is_none()));
@@ -31,8 +31,8 @@ fn input_0_0_0_2() {
 /// Affirm that `Board::shift_group` will successfully merge two cells at the start of the group.
 #[test]
 fn input_2_2_0_0() {
-    let mut row = vec![Some(Cell::new(2)), Some(Cell::new(2)), None, None];
-    let result = Board::shift_group(row, Direction::Left).unwrap();
+    let row = vec![Some(Cell::new(2)), Some(Cell::new(2)), None, None];
+    let result = Board::shift_group(row, Direction::Left, &Powers).unwrap();
     let mut iter = result.iter();
     assert_eq!(Some(Cell::new(4)), *iter.next().unwrap());
     assert!(iter.all(|cell| cell.is_none()));
@@ -42,13 +42,13 @@ fn input_2_2_0_0() {
 /// values, as neither a shift or merge can be performed.
 #[test]
 fn input_2_4_8_16() {
-    let mut row = vec![
+    let row = vec![
         Some(Cell::new(2)),
         Some(Cell::new(4)),
         Some(Cell::new(8)),
         Some(Cell::new(16)),
     ];
-    let result = Board::shift_group(row, Direction::Left);
+    let result = Board::shift_group(row, Direction::Left, &Powers);
     assert!(result.is_none());
 }
 
@@ -56,13 +56,13 @@ fn input_2_4_8_16() {
 /// cells with like values, as no merging or shifting can be performed.
 #[test]
 fn input_2_4_2_4() {
-    let mut row = vec![
+    let row = vec![
         Some(Cell::new(2)),
         Some(Cell::new(4)),
         Some(Cell::new(2)),
         Some(Cell::new(4)),
     ];
-    let result = Board::shift_group(row, Direction::Left);
+    let result = Board::shift_group(row, Direction::Left, &Powers);
     assert!(result.is_none());
 }
 
@@ -70,13 +70,13 @@ fn input_2_4_2_4() {
 /// like values. Only one merge should be performed, and the group will shift.
 #[test]
 fn input_2_4_4_2() {
-    let mut row = vec![
+    let row = vec![
         Some(Cell::new(2)),
         Some(Cell::new(4)),
         Some(Cell::new(4)),
         Some(Cell::new(2)),
     ];
-    let result = Board::shift_group(row, Direction::Left).unwrap();
+    let result = Board::shift_group(row, Direction::Left, &Powers).unwrap();
     let mut iter = result.iter();
     assert_eq!(Some(Cell::new(2)), *iter.next().unwrap());
     assert_eq!(Some(Cell::new(8)), *iter.next().unwrap());
@@ -88,15 +88,58 @@ fn input_2_4_4_2() {
 /// be performed, and the group will shift.
 #[test]
 fn input_2_2_2_2() {
-    let mut row = vec![
+    let row = vec![
         Some(Cell::new(2)),
         Some(Cell::new(2)),
         Some(Cell::new(2)),
         Some(Cell::new(2)),
     ];
-    let result = Board::shift_group(row, Direction::Left).unwrap();
+    let result = Board::shift_group(row, Direction::Left, &Powers).unwrap();
     let mut iter = result.iter();
     assert_eq!(Some(Cell::new(4)), *iter.next().unwrap());
     assert_eq!(Some(Cell::new(4)), *iter.next().unwrap());
     assert!(iter.all(|cell| cell.is_none()));
 }
+
+/// Affirm that `Board::shift_group` will close every gap in a group, not only the first.
+#[test]
+fn input_2_0_4_8() {
+    let row = vec![
+        Some(Cell::new(2)),
+        None,
+        Some(Cell::new(4)),
+        Some(Cell::new(8)),
+    ];
+    let result = Board::shift_group(row, Direction::Left, &Powers).unwrap();
+    let mut iter = result.iter();
+    assert_eq!(Some(Cell::new(2)), *iter.next().unwrap());
+    assert_eq!(Some(Cell::new(4)), *iter.next().unwrap());
+    assert_eq!(Some(Cell::new(8)), *iter.next().unwrap());
+    assert!(iter.all(|cell| cell.is_none()));
+}
+
+/// Affirm that `Board::shift_group` will shift towards the end of the group when moving right,
+/// merging the pair of cells nearest to the end first.
+#[test]
+fn input_2_2_2_0_right() {
+    let row = vec![
+        Some(Cell::new(2)),
+        Some(Cell::new(2)),
+        Some(Cell::new(2)),
+        None,
+    ];
+    let result = Board::shift_group(row, Direction::Right, &Powers).unwrap();
+    assert_eq!(
+        vec![None, None, Some(Cell::new(2)), Some(Cell::new(4))],
+        result
+    );
+}
+
+/// Affirm that `Board::shift_group` will return nothing when moving down on a group whose cells
+/// are already positioned at the end.
+#[test]
+fn input_0_0_2_4_down() {
+    let col = vec![None, None, Some(Cell::new(2)), Some(Cell::new(4))];
+    let result = Board::shift_group(col, Direction::Down, &Powers);
+    assert!(result.is_none());
+}
@@ -13,3 +13,70 @@ fn exhaustive() {
 
     board.spawn().unwrap_err();
 }
+
+/// Affirm that cells are never spawned or placed on walls.
+#[test]
+fn skips_walls() {
+    let mut board = "#,0/0,#".parse::<Board>().unwrap();
+    board.spawn().unwrap();
+    board.spawn().unwrap();
+    board.spawn().unwrap_err();
+
+    assert!(board.grid.get(0, 0).unwrap().is_none());
+    assert!(board.grid.get(1, 1).unwrap().is_none());
+    assert_eq!("#", &board.notation()[..1]);
+    board.place((1, 1), Cell::new(2)).unwrap_err();
+}
+
+/// Affirm that special cells are spawned by their chances in the rules.
+#[test]
+fn specials() {
+    let rules = Rules {
+        specials: [(Special::Bomb, 1.0)].into_iter().collect(),
+        ..Rules::default()
+    };
+    let board = Board::with_rules(rules, 1);
+    assert_eq!(15, board.count_empty());
+    assert!(board.notation().contains('B'));
+
+    let rules = Rules {
+        specials: [(Special::Joker, 0.0)].into_iter().collect(),
+        ..Rules::default()
+    };
+    let mut board = Board::with_rules(rules, 1);
+    while board.spawn().is_ok() {}
+    assert!(!board.notation().contains('J'));
+}
+
+/// Affirm that cells are only spawned on the cells of a hexagonal board, never on the corners of
+/// its grid.
+#[test]
+fn hex() {
+    let rules = Rules {
+        rows: 5,
+        cols: 5,
+        shape: Shape::Hex,
+        ..Rules::default()
+    };
+    let mut board = Board::with_rules(rules, 1);
+    for _ in 1..19 {
+        board.spawn().unwrap();
+    }
+    board.spawn().unwrap_err();
+    assert!(board.notation().starts_with(".,.,"));
+}
+
+/// Affirm that passing spawns a cell without shifting the board, and can be undone.
+#[test]
+fn pass() {
+    let mut board = "2,2/0,0".parse::<Board>().unwrap();
+    match board.pass().unwrap().as_slice() {
+        [Event::Spawn { at, .. }] => assert_eq!(1, at.0),
+        events => panic!("unexpected events: {:?}", events),
+    }
+    assert_eq!(1, board.count_empty());
+    assert_eq!(0, board.score());
+
+    board.movement(Move::Undo).unwrap();
+    assert_eq!("2,2/0,0", board.notation());
+}
@@ -17,7 +17,9 @@ fn exhaustive() {
 #[test]
 fn corner_bottom_right() {
     let mut board = Board::default();
-    board.spawn_at_many(vec![(BOARD_ROWS - 1, BOARD_COLS - 1)]);
+    board
+        .spawn_at_many(vec![(BOARD_ROWS - 1, BOARD_COLS - 1)])
+        .unwrap();
     let mut cells = board.grid.elements_row_major_iter();
     for _ in 0..((BOARD_COLS * BOARD_ROWS) - 1) {
         assert!(cells.next().unwrap().is_none());
@@ -29,7 +31,7 @@ fn corner_bottom_right() {
 #[test]
 fn corner_bottom_left() {
     let mut board = Board::default();
-    board.spawn_at_many(vec![(BOARD_ROWS - 1, 0)]);
+    board.spawn_at_many(vec![(BOARD_ROWS - 1, 0)]).unwrap();
     let mut cells = board.grid.elements_row_major_iter();
     for _ in 0..BOARD_ROWS - 1 {
         for _ in 0..BOARD_COLS {
@@ -44,7 +46,7 @@ fn corner_bottom_left() {
 #[test]
 fn corner_top_left() {
     let mut board = Board::default();
-    board.spawn_at_many(vec![(0, 0)]);
+    board.spawn_at_many(vec![(0, 0)]).unwrap();
     let mut cells = board.grid.elements_row_major_iter();
     assert!(cells.next().unwrap().is_some());
     assert!(cells.all(|cell| cell.is_none()));
@@ -54,7 +56,7 @@ fn corner_top_left() {
 #[test]
 fn corner_top_right() {
     let mut board = Board::default();
-    board.spawn_at_many(vec![(0, BOARD_COLS - 1)]);
+    board.spawn_at_many(vec![(0, BOARD_COLS - 1)]).unwrap();
     let mut cells = board.grid.elements_row_major_iter();
     for _ in 0..BOARD_COLS - 1 {
         assert!(cells.next().unwrap().is_none());
@@ -0,0 +1,41 @@
+use super::*;
+
+/// Affirm that the adversary spawns whichever value leaves the board unable to be shifted.
+#[test]
+fn adversarial() {
+    let mut board = "2,4/8,0".parse::<Board>().unwrap();
+    board.set_spawner(Adversarial { depth: 0 });
+    board.pass().unwrap();
+    assert_eq!("2,4/8,2", board.notation());
+    assert!(board.legal_moves().is_empty());
+
+    let mut board = "4,2/8,0".parse::<Board>().unwrap();
+    board.set_spawner(Adversarial { depth: 0 });
+    board.pass().unwrap();
+    assert_eq!("4,2/8,4", board.notation());
+}
+
+/// Affirm that a script spawns its cells where written, or at the first empty cell if that is
+/// occupied, and that nothing is spawned once it runs out.
+#[test]
+fn scripted() {
+    let mut board = "2,0/0,0".parse::<Board>().unwrap();
+    board.set_spawner(Scripted::new(vec![
+        (Cell::new(4), Some((1, 1))),
+        (Cell::new(8), Some((1, 0))),
+    ]));
+
+    board.movement(Move::Shift(Direction::Right)).unwrap();
+    assert_eq!("0,2/0,4", board.notation());
+    board.movement(Move::Shift(Direction::Left)).unwrap();
+    assert_eq!("2,8/4,0", board.notation());
+    match board
+        .movement(Move::Shift(Direction::Down))
+        .unwrap()
+        .as_slice()
+    {
+        [Event::Slide { .. }, ..] => {}
+        events => panic!("unexpected events: {:?}", events),
+    }
+    assert!(board.pass().is_err());
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+/// Affirm that a board which can be shifted is still being played.
+#[test]
+fn playing() {
+    let board = "2,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().unwrap();
+    assert_eq!(Status::Playing, board.status());
+}
+
+/// Affirm that a full board with no like neighbours is lost and has no legal moves.
+#[test]
+fn lost() {
+    let board = "2,4,2,4/4,2,4,2/2,4,2,4/4,2,4,2".parse::<Board>().unwrap();
+    assert!(board.legal_moves().is_empty());
+    assert_eq!(Status::Lost, board.status());
+}
+
+/// Affirm that a board holding the winning value is won.
+#[test]
+fn won() {
+    let board = "2048,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0"
+        .parse::<Board>()
+        .unwrap();
+    assert_eq!(Status::Won, board.status());
+}
+
+/// Affirm that only the directions which change the board are legal.
+#[test]
+fn legal_moves() {
+    let board = "2,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().unwrap();
+    assert_eq!(vec![Direction::Down, Direction::Right], board.legal_moves());
+}
+
+/// Affirm that the legal moves of a hexagonal board are taken from its six directions.
+#[test]
+fn legal_moves_hex() {
+    let board = ".,0,2/0,0,0/0,0,.".parse::<Board>().unwrap();
+    assert_eq!(
+        vec![Direction::Left, Direction::DownLeft, Direction::DownRight],
+        board.legal_moves()
+    );
+}
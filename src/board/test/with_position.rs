@@ -0,0 +1,28 @@
+use super::*;
+
+/// Affirm that a board starts from the position written, and spawns identical cells for
+/// identical moves given the same seed.
+#[test]
+fn deterministic() {
+    let notation = "2,2,0,0/0,0,0,0/0,0,0,0/0,0,0,4";
+    let mut a = Board::with_position(Rules::default(), notation, 42).unwrap();
+    let mut b = Board::with_position(Rules::default(), notation, 42).unwrap();
+    assert_eq!(notation, a.notation());
+
+    for dir in [Direction::Left, Direction::Up, Direction::Right] {
+        assert_eq!(a.movement(Move::Shift(dir)), b.movement(Move::Shift(dir)));
+        assert_eq!(a.notation(), b.notation());
+    }
+}
+
+/// Affirm that a position which does not fit the rules is rejected.
+#[test]
+fn mismatched() {
+    assert!(Board::with_position(Rules::default(), "2,0/0,0", 1).is_err());
+    let rules = Rules {
+        walls: vec![(0, 1)],
+        ..Rules::default()
+    };
+    assert!(Board::with_position(rules.clone(), "2,#,0,0/0,0,0,0/0,0,0,0/0,0,0,0", 1).is_ok());
+    assert!(Board::with_position(rules, "2,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0", 1).is_err());
+}
@@ -0,0 +1,19 @@
+use super::*;
+
+/// Affirm that boards created with the same seed spawn identical cells for identical moves.
+#[test]
+fn deterministic() {
+    let mut a = Board::with_seed(42);
+    let mut b = Board::with_seed(42);
+    assert_eq!(a.notation(), b.notation());
+
+    for dir in [
+        Direction::Left,
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+    ] {
+        assert_eq!(a.movement(Move::Shift(dir)), b.movement(Move::Shift(dir)));
+        assert_eq!(a.notation(), b.notation());
+    }
+}
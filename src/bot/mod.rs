@@ -0,0 +1,28 @@
+use crate::board::Board;
+use crate::Direction;
+
+/// Chooses the direction in which to shift the board, or `None` if no shift can be performed.
+///
+/// Each legal shift is looked at one move ahead: the shift leaving the most empty cells is
+/// preferred, with ties broken by the score earned and then by the order of the directions of the
+/// rules.
+///
+/// # Arguments
+///
+/// * `board` - the board to choose a shift for
+pub fn best_move(board: &Board) -> Option<Direction> {
+    let mut best: Option<(Direction, (usize, usize))> = None;
+
+    for dir in board.rules().directions() {
+        if let Some(next) = board.shifted(dir) {
+            let key = (next.count_empty(), next.score());
+            if best.is_none_or(|(_, k)| key > k) {
+                best = Some((dir, key));
+            }
+        }
+    }
+    best.map(|(dir, _)| dir)
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,15 @@
+use super::*;
+
+/// Affirm that `bot::best_move` prefers the shift which merges cells and so frees the most space.
+#[test]
+fn prefers_merge() {
+    let board = "2,2,0,0/4,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().unwrap();
+    assert_eq!(Some(Direction::Left), best_move(&board));
+}
+
+/// Affirm that `bot::best_move` reports nothing for a board which cannot be shifted.
+#[test]
+fn no_legal_moves() {
+    let board = "2,4,2,4/4,2,4,2/2,4,2,4/4,2,4,2".parse::<Board>().unwrap();
+    assert_eq!(None, best_move(&board));
+}
@@ -0,0 +1,3 @@
+use super::*;
+
+mod best_move;
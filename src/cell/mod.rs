@@ -1,13 +1,43 @@
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
 use rand::Rng;
+use serde::{Serialize, Serializer};
 
-/// The representation of a cell on the game board.
+mod rule;
+mod special;
+
+pub use rule::{Fibonacci, MergeRule, Powers, Triples};
+pub use special::Special;
+
+/// Type representing the value of a cell, which may grow without limit.
+pub type Value = BigUint;
+
+/// The representation of a cell on the game board, holding either a value or, if it is special,
+/// its kind (in which case its value is zero).
 #[derive(Clone, Debug, PartialEq)]
-pub struct Cell(usize);
+pub struct Cell(Value, Option<Special>);
 
 /// Implementation of the `Display` trait for `Cell`.
 impl std::fmt::Display for Cell {
+    /// Writes the value of the cell, or the symbol of its kind if it is special.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        match self.1 {
+            Some(special) => write!(f, "{}", special.symbol()),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+/// Implementation of the `Serialize` trait for `Cell`.
+impl Serialize for Cell {
+    /// Serializes the value of the cell as a number, or as a string of its decimal digits if it
+    /// does not fit in 64 bits, or the name of its kind if it is special.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self.1, self.0.to_u64()) {
+            (Some(special), _) => special.serialize(serializer),
+            (None, Some(value)) => value.serialize(serializer),
+            (None, None) => self.0.to_string().serialize(serializer),
+        }
     }
 }
 
@@ -20,19 +50,44 @@ impl Drop for Cell {
 impl Default for Cell {
     /// Randomly initializes the value of the cell to either two or four.
     fn default() -> Self {
+        Self::random(&mut rand::thread_rng())
+    }
+}
+
+impl Cell {
+    /// Initializes a cell to either two or four, drawing from the specified random number
+    /// generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - the source of randomness used to pick the value
+    pub fn random(rng: &mut (impl Rng + ?Sized)) -> Self {
         const CHANCE_OF_FOUR: f64 = 0.1;
-        Self(match rand::thread_rng().gen_bool(CHANCE_OF_FOUR) {
+        let value: u32 = match rng.gen_bool(CHANCE_OF_FOUR) {
             true => 4,
             false => 2,
-        })
+        };
+        Self(value.into(), None)
     }
-}
 
-impl Cell {
-    /// Returns the value held by the cell.
+    /// Returns the value held by the cell, which is zero if the cell is special.
     #[inline(always)]
-    pub fn value(&self) -> usize {
-        self.0
+    pub fn value(&self) -> &Value {
+        &self.0
+    }
+
+    /// Returns the kind of the cell, if it is special.
+    pub fn special(&self) -> Option<Special> {
+        self.1
+    }
+
+    /// Returns a special cell of the specified kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `special` - the kind of the new cell
+    pub fn of_special(special: Special) -> Self {
+        Self(Value::default(), Some(special))
     }
 
     /// Allows the instantiation of a cell with a specified value given that value is a power of
@@ -41,48 +96,126 @@ impl Cell {
     /// # Arguments
     ///
     /// * `value` - the power of two value to initialize the new cell to
+    #[allow(dead_code)]
     pub fn new(value: usize) -> Self {
-        if value.count_ones() != 1 || value < 2 {
+        let value = Value::from(value);
+        if !Cell::is_valid(&value) {
             panic!();
         }
-        Self(value)
+        Self(value, None)
     }
 
-    /// Increases the value of the cell by a power of two.
-    fn grow(&mut self) -> Result<(), ()> {
-        const MSB_SET: usize = 1 << (usize::BITS - 1);
-
-        // assert that the value is a power of two greater than one
-        assert_eq!(1, self.0.count_ones());
-        assert!(self.0 > 1);
+    /// Returns a cell holding the specified value, if a cell may hold it under the merge rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to initialize the new cell to
+    /// * `rule` - the merge rule of the game the cell belongs to
+    pub fn of(value: usize, rule: &dyn MergeRule) -> Option<Self> {
+        Cell::with_value(value.into(), rule)
+    }
 
-        if MSB_SET == self.0 {
-            return Err(());
+    /// Returns a cell holding the specified value, which may be too large for a `usize`, if a cell
+    /// may hold it under the merge rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to initialize the new cell to
+    /// * `rule` - the merge rule of the game the cell belongs to
+    pub fn with_value(value: Value, rule: &dyn MergeRule) -> Option<Self> {
+        match rule.is_valid(&value) {
+            true => Some(Self(value, None)),
+            false => None,
         }
+    }
+
+    /// Returns whether a cell may hold the specified value (i.e. a power of two greater than one)
+    /// in the classic game.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to check
+    pub fn is_valid(value: &Value) -> bool {
+        value.count_ones() == 1 && value.bits() >= 2
+    }
+
+    /// Increases the value of the cell by a power of two. Values are unbounded, so the cell can
+    /// always grow.
+    fn grow(&mut self) {
+        // assert that the value is a power of two greater than one
+        assert!(Cell::is_valid(&self.0));
 
         self.0 <<= 1;
-        Ok(())
     }
 
-    /// Iff the cells have equal value, then `self` will grow whereas `other` will be dropped.
+    /// Returns the outcome of merging a run of cells, or `None` if they cannot merge. A run
+    /// holding a special cell is a pair, merged by the rules of the special kind: a bomb clears
+    /// both cells, whereas a joker or a doubler merges only with a cell holding a value. Otherwise,
+    /// the run is merged by the merge rule.
+    ///
+    /// # Arguments
+    /// * `run` - the cells of the run, beginning with the cell being merged into
+    /// * `rule` - the merge rule deciding whether and into what the cells merge
+    ///
+    /// # Returns
+    /// * `Some(Some(Cell))` - the cells merge to form the returned cell
+    /// * `Some(None)` - the cells clear each other
+    /// * `None` - the cells do not merge
+    pub fn merged(run: &[&Self], rule: &dyn MergeRule) -> Option<Option<Self>> {
+        let value = |value: Option<Value>| value.map(|value| Some(Self(value, None)));
+        match run {
+            [a, b] if a.1.is_some() || b.1.is_some() => match (a.1, b.1) {
+                (Some(Special::Bomb), _) | (_, Some(Special::Bomb)) => Some(None),
+                (Some(Special::Joker), None) => value(rule.promote(&b.0)),
+                (None, Some(Special::Joker)) => value(rule.promote(&a.0)),
+                (Some(Special::Doubler), None) | (None, Some(Special::Doubler)) => {
+                    let doubled = (&a.0).max(&b.0) << 1;
+                    value(Some(doubled).filter(|doubled| rule.is_valid(doubled)))
+                }
+                _ => None,
+            },
+            _ if run.iter().any(|cell| cell.1.is_some()) => None,
+            _ => value(rule.merge(&run.iter().map(|cell| &cell.0).collect::<Vec<&Value>>())),
+        }
+    }
+
+    /// Iff the cells are able to merge, then `self` and `others` will be dropped in favour of the
+    /// cell they form, if any.
     ///
     /// # Arguments
-    /// * `other` - the other cell to merge with (that will be dropped on merge)
+    /// * `others` - the other cells of the run to merge with (that will be dropped on merge)
+    /// * `rule` - the merge rule deciding whether and into what the cells merge
     ///
     /// # Returns
-    /// * `Ok(())` -
-    /// * `Err(Self)` - return `other`, as it was not successfully merged
-    pub fn merge(&mut self, other: Self) -> Result<(), Self> {
-        match *self == other {
-            true => {
-                self.grow().unwrap();
-                drop(other);
-                Ok(())
+    /// * `Ok(Option<Self>)` - the cell formed by the merge, or `None` if the cells cleared
+    /// * `Err((Self, Vec<Self>))` - return `self` and `others`, as they were not successfully
+    ///   merged
+    pub fn merge(
+        self,
+        others: Vec<Self>,
+        rule: &dyn MergeRule,
+    ) -> Result<Option<Self>, (Self, Vec<Self>)> {
+        let run = std::iter::once(&self)
+            .chain(others.iter())
+            .collect::<Vec<&Self>>();
+        match Cell::merged(&run, rule) {
+            Some(merged) => {
+                drop(others);
+                Ok(merged)
             }
-            _ => Err(other),
+            None => Err((self, others)),
         }
     }
 }
 
+/// Returns a value as a `usize`, saturating at `usize::MAX` if it does not fit.
+///
+/// # Arguments
+///
+/// * `value` - the value to convert
+pub fn saturate(value: &Value) -> usize {
+    value.to_usize().unwrap_or(usize::MAX)
+}
+
 #[cfg(test)]
 mod test;
@@ -0,0 +1,204 @@
+use rand::{Rng, RngCore};
+
+use super::{Cell, Value};
+
+/// The rules deciding which values cells may hold, which cells merge and what they merge into,
+/// what a merge scores and which cells are spawned.
+pub trait MergeRule: Send + Sync {
+    /// Returns whether a cell may hold the specified value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to check
+    fn is_valid(&self, value: &Value) -> bool;
+
+    /// Returns the number of cells in a row which merge together.
+    fn run(&self) -> usize {
+        2
+    }
+
+    /// Returns the value formed by merging a run of cells, or `None` if they cannot merge.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - the values of the run of cells, beginning with the cell being merged into
+    fn merge(&self, values: &[&Value]) -> Option<Value>;
+
+    /// Returns the value formed by merging a cell with a joker, i.e. the value it would form by
+    /// merging with the cells it merges with, or `None` if it cannot grow.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value of the cell merging with the joker
+    fn promote(&self, value: &Value) -> Option<Value> {
+        self.merge(&vec![value; self.run()])
+    }
+
+    /// Returns the points scored by a merge forming the specified value.
+    ///
+    /// # Arguments
+    ///
+    /// * `merged` - the value formed by the merge
+    fn score(&self, merged: &Value) -> Value {
+        merged.clone()
+    }
+
+    /// Draws the value of a newly spawned cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - the source of randomness used to pick the value
+    fn spawn(&self, rng: &mut dyn RngCore) -> usize;
+
+    /// Returns every value which may be drawn for a newly spawned cell.
+    fn spawns(&self) -> &'static [usize];
+
+    /// Returns the value which must be reached to win, unless the rules of a game say otherwise.
+    fn win_value(&self) -> usize;
+}
+
+/// The rule of the classic game: cells hold powers of two, and equal cells merge into their sum.
+pub struct Powers;
+
+/// Implementation of the `MergeRule` trait for `Powers`.
+impl MergeRule for Powers {
+    fn is_valid(&self, value: &Value) -> bool {
+        Cell::is_valid(value)
+    }
+
+    fn merge(&self, values: &[&Value]) -> Option<Value> {
+        let [a, b] = values else {
+            return None;
+        };
+        let mut cell = Cell((*a).clone(), None);
+        match a == b {
+            true => {
+                cell.grow();
+                Some(std::mem::take(&mut cell.0))
+            }
+            false => None,
+        }
+    }
+
+    fn spawn(&self, rng: &mut dyn RngCore) -> usize {
+        super::saturate(Cell::random(rng).value())
+    }
+
+    fn spawns(&self) -> &'static [usize] {
+        &[2, 4]
+    }
+
+    fn win_value(&self) -> usize {
+        2048
+    }
+}
+
+/// The rule of the Fibonacci variant: cells hold Fibonacci numbers, and cells holding consecutive
+/// Fibonacci numbers (e.g. one and one, two and three) merge into their sum, the next number.
+pub struct Fibonacci;
+
+/// Implementation of the `MergeRule` trait for `Fibonacci`.
+impl MergeRule for Fibonacci {
+    fn is_valid(&self, value: &Value) -> bool {
+        Fibonacci::successor(value).is_some()
+    }
+
+    fn merge(&self, values: &[&Value]) -> Option<Value> {
+        let [a, b] = *values else {
+            return None;
+        };
+        // the only pairs of Fibonacci numbers which sum to another are consecutive ones (counting
+        // the first two ones as consecutive)
+        let sum = a + b;
+        match self.is_valid(a) && self.is_valid(b) && self.is_valid(&sum) {
+            true => Some(sum),
+            false => None,
+        }
+    }
+
+    fn promote(&self, value: &Value) -> Option<Value> {
+        // the next Fibonacci number, formed by merging with the one before it
+        Fibonacci::successor(value)
+    }
+
+    fn spawn(&self, rng: &mut dyn RngCore) -> usize {
+        const CHANCE_OF_TWO: f64 = 0.1;
+        match rng.gen_bool(CHANCE_OF_TWO) {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    fn spawns(&self) -> &'static [usize] {
+        &[1, 2]
+    }
+
+    fn win_value(&self) -> usize {
+        2584
+    }
+}
+
+impl Fibonacci {
+    /// Returns the Fibonacci number following the specified value, or `None` if the value is not
+    /// a Fibonacci number (counting one only once).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to find the successor of
+    fn successor(value: &Value) -> Option<Value> {
+        let (mut a, mut b) = (Value::from(1u32), Value::from(2u32));
+        while a < *value {
+            let next = &a + &b;
+            (a, b) = (b, next);
+        }
+        match a == *value {
+            true => Some(b),
+            false => None,
+        }
+    }
+}
+
+/// The rule of the base-3 variant: cells hold powers of three, and three equal cells in a row
+/// merge into their sum, the next power.
+pub struct Triples;
+
+/// Implementation of the `MergeRule` trait for `Triples`.
+impl MergeRule for Triples {
+    fn is_valid(&self, value: &Value) -> bool {
+        let mut power = Value::from(3u32);
+        while power < *value {
+            power *= 3u32;
+        }
+        power == *value
+    }
+
+    fn run(&self) -> usize {
+        3
+    }
+
+    fn merge(&self, values: &[&Value]) -> Option<Value> {
+        let [a, b, c] = *values else {
+            return None;
+        };
+        match a == b && b == c {
+            true => Some(a * 3u32),
+            false => None,
+        }
+    }
+
+    fn spawn(&self, rng: &mut dyn RngCore) -> usize {
+        const CHANCE_OF_NINE: f64 = 0.1;
+        match rng.gen_bool(CHANCE_OF_NINE) {
+            true => 9,
+            false => 3,
+        }
+    }
+
+    fn spawns(&self) -> &'static [usize] {
+        &[3, 9]
+    }
+
+    fn win_value(&self) -> usize {
+        2187
+    }
+}
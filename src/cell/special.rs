@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+/// The representation of each kind of special cell. A special cell holds no value of its own, but
+/// merges with other cells by its own rules rather than by those of the variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Special {
+    /// Merges with any cell holding a value, forming what that cell would form by merging with
+    /// cells of its own value.
+    Joker,
+    /// Merges with any cell, clearing both.
+    Bomb,
+    /// Merges with any cell holding a value, doubling it where the variant allows.
+    Doubler,
+}
+
+/// Implementation of the `Display` trait for `Special`.
+impl std::fmt::Display for Special {
+    /// Writes the special as its lowercase name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Special::Joker => "joker",
+                Special::Bomb => "bomb",
+                Special::Doubler => "doubler",
+            }
+        )
+    }
+}
+
+/// Implementation of the `FromStr` trait for `Special`.
+impl std::str::FromStr for Special {
+    type Err = ();
+
+    /// Parses a special from its lowercase name, as written by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Special::ALL
+            .into_iter()
+            .find(|special| special.to_string() == s)
+            .ok_or(())
+    }
+}
+
+impl Special {
+    /// Every kind of special cell.
+    pub const ALL: [Special; 3] = [Special::Joker, Special::Bomb, Special::Doubler];
+
+    /// Returns the symbol a cell of the special kind is written as on the board.
+    pub fn symbol(self) -> char {
+        match self {
+            Special::Joker => 'J',
+            Special::Bomb => 'B',
+            Special::Doubler => 'D',
+        }
+    }
+}
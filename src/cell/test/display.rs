@@ -3,5 +3,33 @@ use super::*;
 /// Affirm that the implementation of the `Display` trait for `Cell` works properly.
 #[test]
 fn to_string() {
-    assert_eq!("64", Cell(64).to_string());
+    assert_eq!("64", Cell(Value::from(64usize), None).to_string());
+}
+
+/// Affirm that special cells are displayed as the symbol of their kind, and serialized as its
+/// name.
+#[test]
+fn special() {
+    assert_eq!("J", Cell::of_special(Special::Joker).to_string());
+    assert_eq!("B", Cell::of_special(Special::Bomb).to_string());
+    assert_eq!("D", Cell::of_special(Special::Doubler).to_string());
+    assert_eq!(
+        "[64,\"joker\"]",
+        serde_json::to_string(&[
+            Cell(Value::from(64usize), None),
+            Cell::of_special(Special::Joker)
+        ])
+        .unwrap()
+    );
+}
+
+/// Affirm that values too large for 64 bits are displayed exactly, and serialized as strings.
+#[test]
+fn unbounded() {
+    let cell = Cell(Value::from(2u32).pow(64), None);
+    assert_eq!("18446744073709551616", cell.to_string());
+    assert_eq!(
+        "\"18446744073709551616\"",
+        serde_json::to_string(&cell).unwrap()
+    );
 }
@@ -4,22 +4,22 @@ use super::*;
 #[test]
 #[should_panic]
 fn input_0() {
-    Cell(0).grow();
+    Cell(Value::from(0usize), None).grow();
 }
 
 /// Affirm that `Cell::grow()` will panic if the cell unexpectedly has a value of one.
 #[test]
 #[should_panic]
 fn input_1() {
-    Cell(1).grow();
+    Cell(Value::from(1usize), None).grow();
 }
 
 /// Affirm that `Cell::grow()` will return four for a cell with the value of two.
 #[test]
 fn input_2() {
-    let mut cell = Cell(2);
-    cell.grow().unwrap();
-    assert_eq!(4, cell.0);
+    let mut cell = Cell(Value::from(2usize), None);
+    cell.grow();
+    assert_eq!(Value::from(4usize), cell.0);
 }
 
 /// Affirm that `Cell::grow()` will panic if the cell unexpectedly has a value that is a non-power
@@ -27,15 +27,15 @@ fn input_2() {
 #[test]
 #[should_panic]
 fn input_3() {
-    Cell(3).grow();
+    Cell(Value::from(3usize), None).grow();
 }
 
 /// Affirm that `Cell::grow()` will return eight for a cell with the value of four.
 #[test]
 fn input_4() {
-    let mut cell = Cell(4);
-    cell.grow().unwrap();
-    assert_eq!(8, cell.0);
+    let mut cell = Cell(Value::from(4usize), None);
+    cell.grow();
+    assert_eq!(Value::from(8usize), cell.0);
 }
 
 /// Affirm that `Cell::grow()` will succeed if the cell value is equal to the second
@@ -44,17 +44,20 @@ fn input_4() {
 fn input_second_msb() {
     const V: usize = 1 << (usize::BITS - 2);
     const E: usize = 1 << (usize::BITS - 1);
-    let mut cell = Cell(V);
-    cell.grow().unwrap();
-    assert_eq!(E, cell.0);
+    let mut cell = Cell(Value::from(V), None);
+    cell.grow();
+    assert_eq!(Value::from(E), cell.0);
 }
 
-/// Affirm that `Cell::grow()` will result in an error if the cell value is equal to the
-/// most-significant set bit.
+/// Affirm that `Cell::grow()` will grow past the most-significant bit of a `usize`, as values are
+/// unbounded.
 #[test]
 fn input_msb() {
     const V: usize = 1 << (usize::BITS - 1);
-    Cell(V).grow().unwrap_err();
+    let mut cell = Cell(Value::from(V), None);
+    cell.grow();
+    cell.grow();
+    assert_eq!(Value::from(V) * 4u32, cell.0);
 }
 
 /// Affirm that `Cell::grow()` will panic if the cell unexpectedly has a value that is a non-power
@@ -62,5 +65,5 @@ fn input_msb() {
 #[test]
 #[should_panic]
 fn input_max() {
-    Cell(usize::MAX).grow();
+    Cell(Value::from(usize::MAX), None).grow();
 }
@@ -6,10 +6,10 @@ use super::*;
 fn with_equal() {
     const V: usize = 2;
 
-    let (mut mergee, mut merger) = (Cell(V), Cell(V));
-    mergee.merge(merger).unwrap();
+    let (mergee, merger) = (Cell(Value::from(V), None), Cell(Value::from(V), None));
+    let merged = mergee.merge(vec![merger], &Powers).unwrap().unwrap();
 
-    assert_eq!(V * 2, mergee.0);
+    assert_eq!(Value::from(V * 2), merged.0);
 }
 
 /// Affirm that an error will occur if `Cell::merge()` is performed on two cells of unequal
@@ -21,9 +21,67 @@ fn with_unequal() {
 
     assert_ne!(A, B);
 
-    let (mut mergee, mut merger) = (Cell(A), Cell(B));
-    merger = mergee.merge(merger).unwrap_err();
+    let (mergee, merger) = (Cell(Value::from(A), None), Cell(Value::from(B), None));
+    let (mergee, mergers) = mergee.merge(vec![merger], &Powers).unwrap_err();
 
-    assert_eq!(A, mergee.0);
-    assert_eq!(B, merger.0);
+    assert_eq!(Value::from(A), mergee.0);
+    assert_eq!(vec![Cell(Value::from(B), None)], mergers);
+}
+
+/// Affirm that a joker merges with a cell holding a value, in either order, forming what the cell
+/// would form by merging under the variant, but not with another special cell.
+#[test]
+fn with_joker() {
+    let joker = Cell::of_special(Special::Joker);
+    let merge = |a: &Cell, b: &Cell, rule: &dyn MergeRule| a.clone().merge(vec![b.clone()], rule);
+
+    assert_eq!(
+        Ok(Some(Cell(Value::from(16usize), None))),
+        merge(&joker, &Cell(Value::from(8usize), None), &Powers)
+    );
+    assert_eq!(
+        Ok(Some(Cell(Value::from(16usize), None))),
+        merge(&Cell(Value::from(8usize), None), &joker, &Powers)
+    );
+    assert_eq!(
+        Ok(Some(Cell(Value::from(13usize), None))),
+        merge(&Cell(Value::from(8usize), None), &joker, &Fibonacci)
+    );
+    assert_eq!(
+        Ok(Some(Cell(Value::from(27usize), None))),
+        merge(&joker, &Cell(Value::from(9usize), None), &Triples)
+    );
+    assert!(merge(&joker, &joker, &Powers).is_err());
+    assert!(merge(&joker, &Cell::of_special(Special::Doubler), &Powers).is_err());
+}
+
+/// Affirm that a bomb clears itself and any cell it merges with.
+#[test]
+fn with_bomb() {
+    let bomb = Cell::of_special(Special::Bomb);
+    for other in [
+        Cell(Value::from(2usize), None),
+        Cell::of_special(Special::Joker),
+        Cell::of_special(Special::Bomb),
+    ] {
+        assert_eq!(Ok(None), bomb.clone().merge(vec![other.clone()], &Powers));
+        assert_eq!(Ok(None), other.merge(vec![bomb.clone()], &Triples));
+    }
+}
+
+/// Affirm that a doubler doubles the cell it merges with, unless the doubled value is not allowed
+/// by the variant.
+#[test]
+fn with_doubler() {
+    let doubler = Cell::of_special(Special::Doubler);
+
+    let merged = Cell(Value::from(64usize), None).merge(vec![doubler.clone()], &Powers);
+    assert_eq!(Ok(Some(Cell(Value::from(128usize), None))), merged);
+    let merged = doubler
+        .clone()
+        .merge(vec![Cell(Value::from(1usize), None)], &Fibonacci);
+    assert_eq!(Ok(Some(Cell(Value::from(2usize), None))), merged);
+    assert!(doubler
+        .merge(vec![Cell(Value::from(3usize), None)], &Fibonacci)
+        .is_err());
 }
@@ -4,3 +4,4 @@ mod display;
 mod grow;
 mod merge;
 mod new;
+mod rule;
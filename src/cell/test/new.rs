@@ -3,7 +3,7 @@ use super::*;
 /// Affirm that `Cell::default()` will initialize the value to either two or four.
 #[test]
 fn default() {
-    assert!([2, 4].contains(&Cell::default().0));
+    assert!([2usize, 4].map(Value::from).contains(&Cell::default().0));
 }
 
 /// Affirm that `Cell::new()` will fail to initialize a cell with a value of zero.
@@ -0,0 +1,94 @@
+use super::*;
+
+/// Returns the value formed by merging a run of cells holding the specified values.
+fn merge(rule: &dyn MergeRule, values: &[usize]) -> Option<usize> {
+    let values = values
+        .iter()
+        .map(|v| Value::from(*v))
+        .collect::<Vec<Value>>();
+    rule.merge(&values.iter().collect::<Vec<&Value>>())
+        .map(|merged| saturate(&merged))
+}
+
+/// Returns whether a cell may hold the specified value.
+fn is_valid(rule: &dyn MergeRule, value: usize) -> bool {
+    rule.is_valid(&Value::from(value))
+}
+
+/// Affirm that `Powers` merges equal cells only, growing past the largest `usize`.
+#[test]
+fn powers() {
+    assert_eq!(Some(8), merge(&Powers, &[4, 4]));
+    assert_eq!(None, merge(&Powers, &[4, 8]));
+
+    let msb = Value::from(1usize << (usize::BITS - 1));
+    let merged = Powers.merge(&[&msb, &msb]).unwrap();
+    assert_eq!(Value::from(2u32).pow(usize::BITS), merged);
+    assert!(Powers.is_valid(&merged));
+    assert!(is_valid(&Powers, 2048));
+    assert!(!is_valid(&Powers, 3));
+}
+
+/// Affirm that `Fibonacci` accepts exactly the Fibonacci numbers.
+#[test]
+fn fibonacci_values() {
+    let valid = (0..100)
+        .filter(|value| is_valid(&Fibonacci, *value))
+        .collect::<Vec<usize>>();
+    assert_eq!(vec![1, 2, 3, 5, 8, 13, 21, 34, 55, 89], valid);
+    assert!(is_valid(&Fibonacci, Fibonacci.win_value()));
+    assert!(is_valid(&Fibonacci, 12200160415121876738));
+    assert!(!is_valid(&Fibonacci, usize::MAX));
+}
+
+/// Affirm that `Fibonacci` merges consecutive Fibonacci numbers, in either order, into their sum.
+#[test]
+fn fibonacci_merge() {
+    assert_eq!(Some(2), merge(&Fibonacci, &[1, 1]));
+    assert_eq!(Some(3), merge(&Fibonacci, &[1, 2]));
+    assert_eq!(Some(5), merge(&Fibonacci, &[3, 2]));
+    assert_eq!(Some(89), merge(&Fibonacci, &[34, 55]));
+    assert_eq!(None, merge(&Fibonacci, &[2, 2]));
+    assert_eq!(None, merge(&Fibonacci, &[1, 3]));
+    assert_eq!(None, merge(&Fibonacci, &[2, 8]));
+}
+
+/// Affirm that `Fibonacci` spawns cells of one or two.
+#[test]
+fn fibonacci_spawn() {
+    let mut rng = rand::thread_rng();
+    assert!((0..100).all(|_| [1, 2].contains(&Fibonacci.spawn(&mut rng))));
+}
+
+/// Affirm that `Triples` accepts exactly the powers of three above one.
+#[test]
+fn triples_values() {
+    let valid = (0..1000)
+        .filter(|value| is_valid(&Triples, *value))
+        .collect::<Vec<usize>>();
+    assert_eq!(vec![3, 9, 27, 81, 243, 729], valid);
+    assert!(is_valid(&Triples, Triples.win_value()));
+    assert!(!is_valid(&Triples, usize::MAX));
+}
+
+/// Affirm that `Triples` merges runs of three equal cells only.
+#[test]
+fn triples_merge() {
+    assert_eq!(3, Triples.run());
+    assert_eq!(Some(9), merge(&Triples, &[3, 3, 3]));
+    assert_eq!(Some(81), merge(&Triples, &[27, 27, 27]));
+    assert_eq!(None, merge(&Triples, &[3, 3]));
+    assert_eq!(None, merge(&Triples, &[3, 3, 9]));
+    assert_eq!(None, merge(&Triples, &[3, 3, 3, 3]));
+}
+
+/// Affirm that every rule only spawns the values it lists as spawned.
+#[test]
+fn spawns() {
+    let mut rng = rand::thread_rng();
+    let rules: [&dyn MergeRule; 3] = [&Powers, &Fibonacci, &Triples];
+    for rule in rules {
+        assert!((0..100).all(|_| rule.spawns().contains(&rule.spawn(&mut rng))));
+        assert!(rule.spawns().iter().all(|value| is_valid(rule, *value)));
+    }
+}
@@ -0,0 +1,270 @@
+use std::io::Write;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::board::{Board, Event, Rules};
+use crate::{parse_input, Move};
+
+/// The representation of the goal of a challenge.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Goal {
+    /// A cell must reach the value.
+    Tile(usize),
+    /// The score must reach the value.
+    Score(usize),
+    /// The board must be cleared down to a single cell.
+    Single,
+}
+
+/// Implementation of the `Display` trait for `Goal`.
+impl std::fmt::Display for Goal {
+    /// Writes the goal as e.g. `reach a 256 tile`, `score 1000 points` or `clear the board down to
+    /// one tile`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Goal::Tile(value) => write!(f, "reach a {} tile", value),
+            Goal::Score(score) => write!(f, "score {} points", score),
+            Goal::Single => write!(f, "clear the board down to one tile"),
+        }
+    }
+}
+
+impl Goal {
+    /// Returns whether the board has met the goal.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the board to check
+    pub fn is_met(&self, board: &Board) -> bool {
+        match self {
+            Goal::Tile(value) => board.max_value() >= *value,
+            Goal::Score(score) => board.score() >= *score,
+            Goal::Single => board.count_filled() == 1,
+        }
+    }
+}
+
+/// The definition of a challenge: a goal to be met within a fixed number of moves, from a seeded
+/// board. Challenges are written as JSON, e.g.
+/// `{"name": "corner", "seed": 7, "start": "2,2,0,0/0,0,0,0/0,0,0,0/0,0,0,0", "moves": 20,
+/// "goal": {"tile": 64}, "undos": 2}`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Challenge {
+    /// The name the challenge is known by.
+    #[serde(default)]
+    pub name: String,
+    /// The seed of the random number generator used for spawning cells.
+    pub seed: u64,
+    /// The rules the challenge is played by.
+    #[serde(default)]
+    pub rules: Rules,
+    /// The notation of the starting position, which must fit the rules; the board starts with a
+    /// spawned cell if none is given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+    /// The number of shifts within which the goal must be met.
+    pub moves: usize,
+    /// The goal of the challenge.
+    pub goal: Goal,
+    /// The number of undos which may be performed over the whole challenge.
+    #[serde(default)]
+    pub undos: usize,
+}
+
+impl Challenge {
+    /// Loads a challenge from its definition in a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - the path of the file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Returns the board the challenge starts from.
+    pub fn board(&self) -> Result<Board, String> {
+        self.rules
+            .validate()
+            .map_err(|_| format!("invalid rules: {}", self.rules))?;
+        match &self.start {
+            Some(start) => Board::with_position(self.rules.clone(), start, self.seed)
+                .map_err(|_| format!("invalid start: {}", start)),
+            None => Ok(Board::with_rules(self.rules.clone(), self.seed)),
+        }
+    }
+}
+
+/// The representation of the result of an attempt at a challenge.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Outcome {
+    /// The name of the challenge.
+    pub name: String,
+    /// Whether the goal was met.
+    pub success: bool,
+    /// The number of shifts made.
+    pub moves: usize,
+    /// The number of undos performed.
+    pub undos: usize,
+    /// The final score.
+    pub score: usize,
+    /// The largest value held by a cell at the end.
+    pub max_value: usize,
+}
+
+/// The representation of every attempt at a challenge.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Results {
+    /// The outcome of each attempt, in the order they were made.
+    pub attempts: Vec<Outcome>,
+}
+
+/// The representation of an attempt at a challenge in progress.
+pub struct Attempt {
+    /// The challenge being attempted.
+    challenge: Challenge,
+    /// The board of the attempt.
+    board: Board,
+    /// The number of shifts made.
+    moves: usize,
+    /// The number of undos performed.
+    undos: usize,
+}
+
+impl Attempt {
+    /// Begins an attempt at a challenge.
+    ///
+    /// # Arguments
+    ///
+    /// * `challenge` - the challenge to attempt
+    pub fn new(challenge: Challenge) -> Result<Self, String> {
+        Ok(Self {
+            board: challenge.board()?,
+            challenge,
+            moves: 0,
+            undos: 0,
+        })
+    }
+
+    /// Returns the board of the attempt.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Returns the number of shifts left.
+    pub fn moves_left(&self) -> usize {
+        self.challenge.moves.saturating_sub(self.moves)
+    }
+
+    /// Returns the number of undos left.
+    pub fn undos_left(&self) -> usize {
+        self.challenge.undos.saturating_sub(self.undos)
+    }
+
+    /// Returns whether the attempt has ended, i.e. the goal has been met, no shifts are left or
+    /// the board can no longer be shifted.
+    pub fn is_over(&self) -> bool {
+        self.challenge.goal.is_met(&self.board)
+            || self.moves_left() == 0
+            || self.board.legal_moves().is_empty()
+    }
+
+    /// Plays a movement, returning the events that followed. Fails if the attempt has ended, no
+    /// undos are left or the movement cannot be performed. An undo does not give back the shift it
+    /// undoes.
+    ///
+    /// # Arguments
+    ///
+    /// * `mov` - the movement to play
+    pub fn play(&mut self, mov: Move) -> Result<Vec<Event>, ()> {
+        if self.is_over() || (mov == Move::Undo && self.undos_left() == 0) {
+            return Err(());
+        }
+        let events = self.board.movement(mov)?;
+        match mov {
+            Move::Shift(_) => self.moves += 1,
+            Move::Undo => self.undos += 1,
+        }
+        Ok(events)
+    }
+
+    /// Returns the result of the attempt as it stands.
+    pub fn outcome(&self) -> Outcome {
+        Outcome {
+            name: self.challenge.name.clone(),
+            success: self.challenge.goal.is_met(&self.board),
+            moves: self.moves,
+            undos: self.undos,
+            score: self.board.score(),
+            max_value: self.board.max_value(),
+        }
+    }
+}
+
+/// Runs the `challenge` mode: attempts the challenge defined in the file given as a positional
+/// argument interactively, reading one movement per line from stdin. The moves and undos left are
+/// shown before every move, and the outcome is recorded in the results file given by `--results`
+/// (or kept in the data directory) once the attempt ends.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let file = args
+        .positional()
+        .first()
+        .ok_or("usage: challenge [--results PATH] FILE")?;
+    let challenge = Challenge::load(Path::new(file))?;
+    let path = crate::data::path(&args, "results", "challenges.json")?;
+
+    println!("goal: {} in {} moves\n", challenge.goal, challenge.moves);
+    let mut attempt = Attempt::new(challenge)?;
+
+    while !attempt.is_over() {
+        println!("{}\n", attempt.board());
+        println!("score: {}", attempt.board().score());
+        println!(
+            "moves left: {}, undos left: {}\n",
+            attempt.moves_left(),
+            attempt.undos_left()
+        );
+
+        print!("move: ");
+        std::io::stdout().flush().unwrap();
+        let mut input = String::new();
+        if std::io::stdin().read_line(&mut input).unwrap() == 0 {
+            // stdin was closed, so the attempt is abandoned
+            return Ok(());
+        }
+        input.truncate(1);
+        let input = input.to_lowercase();
+
+        match parse_input(&input, &attempt.board().rules().directions()) {
+            Ok(mov) => {
+                if attempt.play(mov).is_err() {
+                    println!("invalid move");
+                }
+            }
+            Err(()) => println!("invalid input"),
+        }
+        println!();
+    }
+
+    let outcome = attempt.outcome();
+    println!("{}\n", attempt.board());
+    match outcome.success {
+        true => println!("challenge complete in {} moves", outcome.moves),
+        false => println!("challenge failed after {} moves", outcome.moves),
+    }
+
+    let mut results = crate::data::load::<Results>(&path)?;
+    results.attempts.push(outcome);
+    crate::data::save(&results, &path)
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,27 @@
+use super::*;
+
+/// Affirm that a challenge starts from its position, or otherwise from its seeded board.
+#[test]
+fn start() {
+    let board = challenge(5, 0).board().unwrap();
+    assert_eq!("4,4,4,4/0,0,0,0/0,0,0,0/0,0,0,0", board.notation());
+
+    let challenge = Challenge {
+        start: None,
+        ..challenge(5, 0)
+    };
+    assert_eq!(
+        Board::with_seed(3).notation(),
+        challenge.board().unwrap().notation()
+    );
+}
+
+/// Affirm that a challenge whose position does not fit its rules is rejected.
+#[test]
+fn invalid_start() {
+    let challenge = Challenge {
+        start: Some("4,4/0,0".to_string()),
+        ..challenge(5, 0)
+    };
+    assert!(challenge.board().is_err());
+}
@@ -0,0 +1,31 @@
+use super::*;
+
+/// Affirm that a challenge is read from its JSON definition, taking the classic rules, no
+/// starting position and no undos unless given.
+#[test]
+fn defaults() {
+    let path = std::env::temp_dir().join(format!("rust2048-challenge-{}.json", std::process::id()));
+    std::fs::write(&path, r#"{"seed": 7, "moves": 30, "goal": {"score": 500}}"#).unwrap();
+    let loaded = Challenge::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let challenge = loaded.unwrap();
+    assert_eq!(7, challenge.seed);
+    assert_eq!(Rules::default(), challenge.rules);
+    assert_eq!(None, challenge.start);
+    assert_eq!(30, challenge.moves);
+    assert_eq!(Goal::Score(500), challenge.goal);
+    assert_eq!(0, challenge.undos);
+}
+
+/// Affirm that a missing or malformed challenge file is reported.
+#[test]
+fn invalid() {
+    assert!(Challenge::load(Path::new("/nonexistent/challenge.json")).is_err());
+
+    let path = std::env::temp_dir().join(format!("rust2048-malformed-{}.json", std::process::id()));
+    std::fs::write(&path, r#"{"seed": 7}"#).unwrap();
+    let loaded = Challenge::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert!(loaded.is_err());
+}
@@ -0,0 +1,20 @@
+use super::*;
+use crate::Direction;
+
+mod board;
+mod load;
+mod play;
+
+/// Returns a challenge to reach a 16 tile within the specified number of moves, with the
+/// specified number of undos, from a board holding a row of fours.
+fn challenge(moves: usize, undos: usize) -> Challenge {
+    Challenge {
+        name: "sixteen".to_string(),
+        seed: 3,
+        rules: Rules::default(),
+        start: Some("4,4,4,4/0,0,0,0/0,0,0,0/0,0,0,0".to_string()),
+        moves,
+        goal: Goal::Tile(16),
+        undos,
+    }
+}
@@ -0,0 +1,44 @@
+use super::*;
+
+/// Affirm that an attempt which meets its goal within its moves succeeds, and ends there.
+#[test]
+fn success() {
+    let mut attempt = Attempt::new(challenge(5, 0)).unwrap();
+    attempt.play(Move::Shift(Direction::Left)).unwrap();
+    assert!(!attempt.is_over());
+    attempt.play(Move::Shift(Direction::Left)).unwrap();
+    assert!(attempt.is_over());
+    attempt.play(Move::Shift(Direction::Down)).unwrap_err();
+
+    let outcome = attempt.outcome();
+    assert!(outcome.success);
+    assert_eq!(2, outcome.moves);
+    assert_eq!(16, outcome.max_value);
+}
+
+/// Affirm that an attempt fails once its moves run out without meeting the goal.
+#[test]
+fn failure() {
+    let mut attempt = Attempt::new(challenge(1, 0)).unwrap();
+    attempt.play(Move::Shift(Direction::Left)).unwrap();
+    assert_eq!(0, attempt.moves_left());
+    assert!(attempt.is_over());
+
+    let outcome = attempt.outcome();
+    assert!(!outcome.success);
+    assert_eq!(1, outcome.moves);
+}
+
+/// Affirm that undos are limited by the budget of the challenge, and do not give back moves.
+#[test]
+fn undo_budget() {
+    let mut attempt = Attempt::new(challenge(5, 1)).unwrap();
+    attempt.play(Move::Shift(Direction::Left)).unwrap();
+    attempt.play(Move::Undo).unwrap();
+    assert_eq!(0, attempt.undos_left());
+    assert_eq!(4, attempt.moves_left());
+
+    attempt.play(Move::Shift(Direction::Right)).unwrap();
+    attempt.play(Move::Undo).unwrap_err();
+    assert_eq!(1, attempt.outcome().undos);
+}
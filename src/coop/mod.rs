@@ -0,0 +1,316 @@
+//! Cooperative games: two or more players taking turns, in a fixed order, to move on one board.
+//! Every movement is attributed to the player who made it, undos may be limited for the whole
+//! team, and each turn may be given a time limit, after which it passes to the next player.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+use crate::blitz::{format_time, Clock, SystemClock};
+use crate::board::{Board, Event};
+use crate::stats::Game;
+use crate::{parse_input, Move};
+
+/// The representation of a movement made by a player of a team.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Turn {
+    /// The player who made the movement, as their position in the turn order.
+    pub player: usize,
+    /// The movement made.
+    pub mov: Move,
+}
+
+/// The representation of a team playing one board, taking turns in order.
+pub struct Team<C: Clock> {
+    /// The names of the players, in the order they take their turns.
+    players: Vec<String>,
+    /// The player whose turn it is.
+    turn: usize,
+    /// The number of undos the whole team may perform, if limited.
+    undo_limit: Option<usize>,
+    /// The number of undos performed.
+    undos: usize,
+    /// The time limit of each turn, if turns are timed.
+    turn_time: Option<Duration>,
+    /// The clock turns are timed by.
+    clock: C,
+    /// The time at which the current turn began.
+    began: Duration,
+    /// Every movement made, in order.
+    log: Vec<Turn>,
+    /// The players who made the shifts which can still be undone, oldest first.
+    shifts: Vec<usize>,
+    /// The statistics of the movements of each player.
+    tallies: Vec<Game>,
+}
+
+impl<C: Clock> Team<C> {
+    /// Forms a team, failing if there are fewer than two players, their names are empty or not
+    /// distinct, or turns are timed with no time at all.
+    ///
+    /// # Arguments
+    ///
+    /// * `players` - the names of the players, in the order they take their turns
+    /// * `undo_limit` - the number of undos the whole team may perform, if limited
+    /// * `turn_time` - the time limit of each turn, if turns are timed
+    /// * `clock` - the clock turns are timed by
+    pub fn new(
+        players: Vec<String>,
+        undo_limit: Option<usize>,
+        turn_time: Option<Duration>,
+        clock: C,
+    ) -> Result<Self, String> {
+        if players.len() < 2 {
+            return Err("a team needs at least two players".to_string());
+        }
+        for (idx, name) in players.iter().enumerate() {
+            if name.is_empty() || players[..idx].contains(name) {
+                return Err(format!("invalid player name: `{}`", name));
+            }
+        }
+        if turn_time.is_some_and(|limit| limit.is_zero()) {
+            return Err("a turn time must be at least a second".to_string());
+        }
+        Ok(Self {
+            tallies: vec![Game::default(); players.len()],
+            players,
+            turn: 0,
+            undo_limit,
+            undos: 0,
+            turn_time,
+            began: clock.now(),
+            clock,
+            log: Vec::new(),
+            shifts: Vec::new(),
+        })
+    }
+
+    /// Returns the names of the players, in the order they take their turns.
+    pub fn players(&self) -> &[String] {
+        &self.players
+    }
+
+    /// Returns the position in the turn order of the player of the specified name, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the player
+    pub fn player(&self, name: &str) -> Option<usize> {
+        self.players.iter().position(|player| player == name)
+    }
+
+    /// Returns the player whose turn it is.
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    /// Returns every movement made, in order.
+    pub fn log(&self) -> &[Turn] {
+        &self.log
+    }
+
+    /// Returns the statistics of the movements of a player.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - the position of the player in the turn order
+    pub fn tally(&self, player: usize) -> &Game {
+        &self.tallies[player]
+    }
+
+    /// Returns the statistics of the movements of every player, keyed by name, each finished with
+    /// the outcome of the whole game.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the final board of the team
+    pub fn breakdown(&self, board: &Board) -> BTreeMap<String, Game> {
+        self.players
+            .iter()
+            .zip(&self.tallies)
+            .map(|(name, tally)| {
+                let mut game = tally.clone();
+                game.finish(board);
+                (name.clone(), game)
+            })
+            .collect()
+    }
+
+    /// Returns the number of undos the team has left, if limited.
+    pub fn undos_left(&self) -> Option<usize> {
+        self.undo_limit
+            .map(|limit| limit.saturating_sub(self.undos))
+    }
+
+    /// Returns the time left before the current turn runs out, if turns are timed.
+    pub fn turn_remaining(&self) -> Option<Duration> {
+        self.turn_time
+            .map(|limit| limit.saturating_sub(self.clock.now().saturating_sub(self.began)))
+    }
+
+    /// Passes the turn on for every turn which has run out of time, returning the number of turns
+    /// passed on.
+    pub fn expire(&mut self) -> usize {
+        let Some(limit) = self.turn_time else {
+            return 0;
+        };
+        let elapsed = self.clock.now().saturating_sub(self.began);
+        let expired = (elapsed.as_nanos() / limit.as_nanos()) as usize;
+        for _ in 0..expired {
+            self.began += limit;
+            self.turn = (self.turn + 1) % self.players.len();
+        }
+        expired
+    }
+
+    /// Plays a movement of a player on the team's board, returning the events that followed. A
+    /// shift passes the turn to the next player, while an undo gives it back to the player whose
+    /// shift was undone. Turns which have run out of time are passed on first. Fails if it is not
+    /// the player's turn, the team has no undos left or the movement cannot be performed.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the board of the team
+    /// * `player` - the position of the player in the turn order
+    /// * `mov` - the movement to play
+    pub fn play(&mut self, board: &mut Board, player: usize, mov: Move) -> Result<Vec<Event>, ()> {
+        self.expire();
+        if player != self.turn || (mov == Move::Undo && self.undos_left() == Some(0)) {
+            return Err(());
+        }
+        let events = board.movement(mov)?;
+
+        match mov {
+            Move::Shift(_) => {
+                self.shifts.push(player);
+                if self.shifts.len() > board.rules().undo_limit {
+                    self.shifts.remove(0);
+                }
+                self.turn = (player + 1) % self.players.len();
+            }
+            Move::Undo => {
+                self.undos += 1;
+                self.turn = self.shifts.pop().unwrap_or(self.turn);
+            }
+        }
+        self.log.push(Turn { player, mov });
+        self.tallies[player].observe(mov, &events);
+        self.began = self.clock.now();
+        Ok(events)
+    }
+}
+
+/// Runs the `coop` mode: plays a game interactively as a team, taking turns in the order the
+/// players are given by `--players` (e.g. `--players alice,bob`), reading one movement per line
+/// from stdin. The team may perform only as many undos as `--undos` allows, if given, and each
+/// turn passes to the next player after `--turn-time` seconds, if given. Every movement is
+/// attributed to its player, and the movements of each player are summarized at the end, when
+/// the game is also recorded in the statistics along with the movements of each player.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let players = args
+        .get::<String>("players")?
+        .ok_or("usage: coop --players NAME,NAME... [--undos N] [--turn-time SECS]")?
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .collect::<Vec<String>>();
+    let seed = args.get::<u64>("seed")?.unwrap_or_else(rand::random);
+    let turn_time = args.get::<u64>("turn-time")?.map(Duration::from_secs);
+    let stats_path = crate::data::path(&args, "stats", "stats.json")?;
+
+    let mut board = Board::with_rules(args.rules()?, seed);
+    let mut team = Team::new(
+        players,
+        args.get::<usize>("undos")?,
+        turn_time,
+        SystemClock::default(),
+    )?;
+    let mut game = Game::default();
+
+    // stdin is read on another thread, so that a turn can run out while waiting for a move
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lines() {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    loop {
+        let skipped = team.expire();
+        if skipped > 0 {
+            println!(
+                "out of time: the turn passes to {}\n",
+                team.players()[team.turn()]
+            );
+        }
+        println!("{}\n", board);
+        println!("score: {}", board.score());
+        if let Some(undos) = team.undos_left() {
+            println!("undos left: {}", undos);
+        }
+        match team.turn_remaining() {
+            Some(time) => println!(
+                "turn: {} ({})\n",
+                team.players()[team.turn()],
+                format_time(time)
+            ),
+            None => println!("turn: {}\n", team.players()[team.turn()]),
+        }
+        if board.legal_moves().is_empty() {
+            break;
+        }
+
+        print!("move: ");
+        std::io::stdout().flush().unwrap();
+        let line = match team.turn_remaining() {
+            Some(time) => receiver.recv_timeout(time),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match line {
+            Ok(line) => {
+                let mut input = line.map_err(|e| e.to_string())?;
+                input.truncate(1);
+                let input = input.to_lowercase();
+                match parse_input(&input, &board.rules().directions()) {
+                    Ok(mov) => match team.play(&mut board, team.turn(), mov) {
+                        Ok(events) => game.observe(mov, &events),
+                        Err(()) => println!("invalid move"),
+                    },
+                    Err(()) => println!("invalid input"),
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => println!(),
+            // stdin was closed, so no more moves can be read
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        println!();
+    }
+
+    println!("game over\n");
+    for (idx, name) in team.players().iter().enumerate() {
+        let tally = team.tally(idx);
+        println!(
+            "{}: {} moves, {} merges, {} undos",
+            name, tally.moves, tally.merges, tally.undos
+        );
+    }
+    if team.log().is_empty() {
+        return Ok(());
+    }
+
+    let mut history = crate::data::load::<crate::stats::History>(&stats_path)?;
+    game.finish(&board);
+    game.players = team.breakdown(&board);
+    history.games.push(game);
+    crate::data::save(&history, &stats_path)
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,32 @@
+use super::*;
+
+/// Affirm that the movements of each player are kept under their name along with the outcome of the
+/// game, and are recorded with the statistics of the whole game.
+#[test]
+fn by_name() {
+    let (mut team, mut board, _) = form(&["alice", "bob"], None, None);
+    team.play(&mut board, 0, Move::Shift(Direction::Left))
+        .unwrap();
+    team.play(&mut board, 1, Move::Undo).unwrap();
+    team.play(&mut board, 0, Move::Shift(Direction::Right))
+        .unwrap();
+
+    let breakdown = team.breakdown(&board);
+    assert_eq!(2, breakdown.len());
+    assert_eq!(2, breakdown["alice"].moves);
+    assert_eq!(2, breakdown["alice"].merges);
+    assert_eq!(
+        Some(&1),
+        breakdown["alice"].directions.get(&Direction::Right)
+    );
+    assert_eq!(0, breakdown["bob"].moves);
+    assert_eq!(1, breakdown["bob"].undos);
+    assert_eq!(4, breakdown["bob"].score);
+
+    let game = Game {
+        players: breakdown,
+        ..Game::default()
+    };
+    let json = serde_json::to_string(&game).unwrap();
+    assert_eq!(game, serde_json::from_str::<Game>(&json).unwrap());
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+/// Affirm that nothing is passed on before a turn runs out of time, or if turns are not timed.
+#[test]
+fn in_time() {
+    let (mut team, _, clock) = form(&["alice", "bob"], None, Some(10));
+    clock.advance(9);
+    assert_eq!(0, team.expire());
+    assert_eq!(Some(Duration::from_secs(1)), team.turn_remaining());
+
+    let (mut team, _, clock) = form(&["alice", "bob"], None, None);
+    clock.advance(1000);
+    assert_eq!(0, team.expire());
+    assert_eq!(0, team.turn());
+}
+
+/// Affirm that every turn which runs out of time is passed on, and the next begun in full.
+#[test]
+fn expired() {
+    let (mut team, _, clock) = form(&["alice", "bob", "carol"], None, Some(10));
+    clock.advance(25);
+    assert_eq!(2, team.expire());
+    assert_eq!(2, team.turn());
+    assert_eq!(Some(Duration::from_secs(5)), team.turn_remaining());
+}
+
+/// Affirm that a player whose turn has run out can no longer move, while the next player can.
+#[test]
+fn play_late() {
+    let (mut team, mut board, clock) = form(&["alice", "bob"], None, Some(10));
+    clock.advance(10);
+    assert_eq!(
+        Err(()),
+        team.play(&mut board, 0, Move::Shift(Direction::Left))
+    );
+    team.play(&mut board, 1, Move::Shift(Direction::Left))
+        .unwrap();
+    assert_eq!(0, team.turn());
+    assert_eq!(Some(Duration::from_secs(10)), team.turn_remaining());
+}
@@ -0,0 +1,28 @@
+use super::*;
+use crate::blitz::test::ManualClock;
+use crate::Direction;
+
+mod breakdown;
+mod expire;
+mod new;
+mod play;
+
+/// Forms a team of the specified players, with the specified undo limit and turn time in seconds
+/// if any, returning the team, a board for it to play and the clock its turns are timed by.
+fn form(
+    players: &[&str],
+    undo_limit: Option<usize>,
+    turn_time: Option<u64>,
+) -> (Team<ManualClock>, Board, ManualClock) {
+    let clock = ManualClock::default();
+    clock.advance(100);
+    let team = Team::new(
+        players.iter().map(|name| name.to_string()).collect(),
+        undo_limit,
+        turn_time.map(Duration::from_secs),
+        clock.clone(),
+    )
+    .unwrap();
+    let board = "2,2,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().unwrap();
+    (team, board, clock)
+}
@@ -0,0 +1,35 @@
+use super::*;
+
+/// Affirm that a team begins with the turn of its first player and nothing played.
+#[test]
+fn valid() {
+    let (team, _, _) = form(&["alice", "bob", "carol"], Some(2), None);
+    assert_eq!(["alice", "bob", "carol"], team.players());
+    assert_eq!(0, team.turn());
+    assert_eq!(Some(1), team.player("bob"));
+    assert_eq!(None, team.player("dave"));
+    assert!(team.log().is_empty());
+    assert_eq!(Some(2), team.undos_left());
+    assert_eq!(None, team.turn_remaining());
+}
+
+/// Affirm that a team of fewer than two players, or of empty or repeated names, or whose turns are
+/// timed with no time at all, is not formed.
+#[test]
+fn invalid() {
+    let create = |players: &[&str], turn_time: Option<u64>| {
+        Team::new(
+            players.iter().map(|name| name.to_string()).collect(),
+            None,
+            turn_time.map(Duration::from_secs),
+            SystemClock::default(),
+        )
+        .is_err()
+    };
+    assert!(create(&[], None));
+    assert!(create(&["alice"], None));
+    assert!(create(&["alice", ""], None));
+    assert!(create(&["alice", "bob", "alice"], None));
+    assert!(create(&["alice", "bob"], Some(0)));
+    assert!(!create(&["alice", "bob"], Some(1)));
+}
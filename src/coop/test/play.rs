@@ -0,0 +1,82 @@
+use super::*;
+
+/// Affirm that each shift passes the turn to the next player, in order, and is attributed to the
+/// player who made it.
+#[test]
+fn turns() {
+    let (mut team, mut board, _) = form(&["alice", "bob"], None, None);
+    team.play(&mut board, 0, Move::Shift(Direction::Left))
+        .unwrap();
+    assert_eq!(1, team.turn());
+    team.play(&mut board, 1, Move::Shift(Direction::Down))
+        .unwrap();
+    assert_eq!(0, team.turn());
+
+    assert_eq!(
+        [
+            Turn {
+                player: 0,
+                mov: Move::Shift(Direction::Left)
+            },
+            Turn {
+                player: 1,
+                mov: Move::Shift(Direction::Down)
+            }
+        ],
+        team.log()
+    );
+    assert_eq!(1, team.tally(0).moves);
+    assert_eq!(1, team.tally(0).merges);
+    assert_eq!(1, team.tally(1).moves);
+}
+
+/// Affirm that a player cannot move out of turn, and that the board is then left as it was.
+#[test]
+fn out_of_turn() {
+    let (mut team, mut board, _) = form(&["alice", "bob"], None, None);
+    assert_eq!(
+        Err(()),
+        team.play(&mut board, 1, Move::Shift(Direction::Left))
+    );
+    assert_eq!("2,2,0,0/0,0,0,0/0,0,0,0/0,0,0,0", board.notation());
+    assert!(team.log().is_empty());
+}
+
+/// Affirm that an undo gives the turn back to the player whose shift was undone.
+#[test]
+fn undo() {
+    let (mut team, mut board, _) = form(&["alice", "bob", "carol"], None, None);
+    team.play(&mut board, 0, Move::Shift(Direction::Left))
+        .unwrap();
+    team.play(&mut board, 1, Move::Undo).unwrap();
+    assert_eq!(0, team.turn());
+    assert_eq!("2,2,0,0/0,0,0,0/0,0,0,0/0,0,0,0", board.notation());
+    assert_eq!(1, team.tally(1).undos);
+}
+
+/// Affirm that the team cannot undo beyond its limit.
+#[test]
+fn undo_limit() {
+    let (mut team, mut board, _) = form(&["alice", "bob"], Some(1), None);
+    team.play(&mut board, 0, Move::Shift(Direction::Left))
+        .unwrap();
+    team.play(&mut board, 1, Move::Undo).unwrap();
+    assert_eq!(Some(0), team.undos_left());
+
+    team.play(&mut board, 0, Move::Shift(Direction::Left))
+        .unwrap();
+    assert_eq!(Err(()), team.play(&mut board, 1, Move::Undo));
+    assert_eq!(1, team.turn());
+}
+
+/// Affirm that a movement which cannot be performed keeps the turn.
+#[test]
+fn illegal() {
+    let (mut team, mut board, _) = form(&["alice", "bob"], None, None);
+    assert_eq!(
+        Err(()),
+        team.play(&mut board, 0, Move::Shift(Direction::Up))
+    );
+    assert_eq!(Err(()), team.play(&mut board, 0, Move::Undo));
+    assert_eq!(0, team.turn());
+}
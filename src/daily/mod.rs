@@ -0,0 +1,161 @@
+//! The daily game: a game played by the default rules whose spawns are seeded by the calendar date
+//! (in UTC), so that everyone playing on the same day gets the same spawns. A finished game is
+//! kept as a replay (see the `replay` module), which anyone can verify by playing it again from the
+//! day's seed.
+
+use std::io::Write;
+use std::path::Path;
+
+use time::{Date, Month, OffsetDateTime};
+
+use crate::board::{Board, Rules};
+use crate::parse_input;
+use crate::replay::Replay;
+
+/// Returns the seed of the daily game of a date: the date written as the decimal number
+/// `YYYYMMDD`, e.g. `20240131` for the 31st of January 2024.
+///
+/// # Arguments
+///
+/// * `date` - the date of the game
+pub fn seed(date: Date) -> u64 {
+    let year = u64::try_from(date.year()).unwrap_or(0);
+    year * 10000 + u64::from(u8::from(date.month())) * 100 + u64::from(date.day())
+}
+
+/// Parses a date written as `YYYY-MM-DD`.
+///
+/// # Arguments
+///
+/// * `s` - the date as written
+pub fn parse_date(s: &str) -> Result<Date, String> {
+    let invalid = || format!("invalid date: {}", s);
+    let parts = s
+        .split('-')
+        .map(|part| part.parse::<u16>().map_err(|_| invalid()))
+        .collect::<Result<Vec<u16>, String>>()?;
+    let [year, month, day] = parts[..] else {
+        return Err(invalid());
+    };
+    let month =
+        Month::try_from(u8::try_from(month).map_err(|_| invalid())?).map_err(|_| invalid())?;
+    let day = u8::try_from(day).map_err(|_| invalid())?;
+    Date::from_calendar_date(i32::from(year), month, day).map_err(|_| invalid())
+}
+
+/// Returns the board of the daily game of a date, before any move.
+///
+/// # Arguments
+///
+/// * `date` - the date of the game
+pub fn board(date: Date) -> Board {
+    Board::with_seed(seed(date))
+}
+
+/// Returns the date of the daily game a replay was recorded from, i.e. the date its seed was
+/// derived from. Fails if the seed is not that of any date, or if the game was not played by the
+/// default rules.
+///
+/// # Arguments
+///
+/// * `replay` - the replay
+pub fn date(replay: &Replay) -> Result<Date, String> {
+    if replay.rules != Rules::default() {
+        return Err(format!("not a daily game: played by {}", replay.rules));
+    }
+    let invalid = || format!("not a daily game: seed {} is not a date", replay.seed);
+    let year = i32::try_from(replay.seed / 10000).map_err(|_| invalid())?;
+    let month = Month::try_from((replay.seed / 100 % 100) as u8).map_err(|_| invalid())?;
+    let day = (replay.seed % 100) as u8;
+    let date = Date::from_calendar_date(year, month, day).map_err(|_| invalid())?;
+    match seed(date) == replay.seed {
+        true => Ok(date),
+        false => Err(invalid()),
+    }
+}
+
+/// Runs the `daily` mode. Given `verify` and the path of a replay as positional arguments, the
+/// replay is checked to be of a daily game and verified against the seed of its date. Otherwise,
+/// the daily game of today (or of the date given by `--date`) is played interactively, reading one
+/// movement per line from stdin, and its replay is saved to the file given by `--replay` (or kept
+/// in the data directory, as `daily-YYYY-MM-DD.json`) once the game ends or is abandoned.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let usage =
+        "usage: daily [--date YYYY-MM-DD] [--name NAME] [--replay PATH] | daily verify FILE";
+    match args.positional() {
+        [] => {}
+        [verb, file] if verb == "verify" => {
+            let replay = Replay::load(Path::new(file))?;
+            let date = date(&replay)?;
+            let report = crate::replay::verify(&replay)?;
+            println!("{}\n", report);
+            return match report.divergence {
+                None => {
+                    println!(
+                        "verified: {} scored {} on {}",
+                        replay.name, replay.score, date
+                    );
+                    Ok(())
+                }
+                Some(_) => Err(format!("{} does not match the seed of {}", file, date)),
+            };
+        }
+        _ => return Err(usage.to_string()),
+    }
+
+    let date = match args.get::<String>("date")? {
+        Some(date) => parse_date(&date)?,
+        None => OffsetDateTime::now_utc().date(),
+    };
+    let name = match args.get::<String>("name")? {
+        Some(name) => name,
+        None => std::env::var("USER").unwrap_or_else(|_| "player".to_string()),
+    };
+    let path = crate::data::path(&args, "replay", &format!("daily-{}.json", date))?;
+
+    println!("daily game of {}\n", date);
+    let mut board = board(date);
+    let mut replay = Replay::new(&name, &board, seed(date));
+
+    while !board.legal_moves().is_empty() {
+        println!("{}\n", board);
+        println!("score: {}\n", board.score());
+
+        print!("move: ");
+        std::io::stdout().flush().unwrap();
+        let mut input = String::new();
+        if std::io::stdin().read_line(&mut input).unwrap() == 0 {
+            // stdin was closed, so the game is abandoned as it stands
+            break;
+        }
+        input.truncate(1);
+        let input = input.to_lowercase();
+
+        match parse_input(&input, &board.rules().directions()) {
+            Ok(mov) => match board.movement(mov) {
+                Ok(events) => replay.record(mov, &events, &board),
+                Err(()) => println!("invalid move"),
+            },
+            Err(()) => println!("invalid input"),
+        }
+        println!();
+    }
+
+    println!("{}\n", board);
+    println!("score: {}", board.score());
+    if replay.steps.is_empty() {
+        return Ok(());
+    }
+
+    crate::data::save(&replay, &path)?;
+    println!("replay saved to {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,37 @@
+use super::*;
+
+/// Affirm that a replay of a daily game is dated by its seed, and verified against that seed.
+#[test]
+fn daily() {
+    let replay = replay(seed(day()), &moves(4));
+    assert_eq!(day(), date(&replay).unwrap());
+    assert_eq!(None, crate::replay::verify(&replay).unwrap().divergence);
+}
+
+/// Affirm that a replay is not taken for a daily game if its seed is not that of a date, or if it
+/// was not played by the default rules.
+#[test]
+fn not_daily() {
+    for undated in [0, 20261318, 20260230, u64::MAX] {
+        let mut replay = replay(seed(day()), &[]);
+        replay.seed = undated;
+        assert!(date(&replay).is_err(), "{}", undated);
+    }
+
+    let mut unruly = replay(seed(day()), &[]);
+    unruly.rules.undo_limit = 5;
+    assert!(date(&unruly).err().unwrap().contains("5 undos"));
+}
+
+/// Affirm that a replay of a daily game claiming more than its moves score is dated, but does not
+/// match the seed of its date.
+#[test]
+fn dishonest() {
+    let mut inflated = replay(seed(day()), &moves(4));
+    inflated.score += 4;
+    assert!(date(&inflated).is_ok());
+    assert!(crate::replay::verify(&inflated)
+        .unwrap()
+        .divergence
+        .is_some());
+}
@@ -0,0 +1,10 @@
+use super::*;
+use crate::replay::test::{moves, replay};
+
+mod date;
+mod seed;
+
+/// Returns the date of the daily game the tests record.
+fn day() -> Date {
+    Date::from_calendar_date(2026, Month::October, 18).unwrap()
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+/// Affirm that the seed of a day is its date written as `YYYYMMDD`.
+#[test]
+fn date() {
+    let date = Date::from_calendar_date(2024, Month::January, 31).unwrap();
+    assert_eq!(20240131, seed(date));
+    assert_eq!(date, parse_date("2024-01-31").unwrap());
+    assert_eq!(date, parse_date(&date.to_string()).unwrap());
+}
+
+/// Affirm that dates which are not written as `YYYY-MM-DD`, or do not exist, are rejected.
+#[test]
+fn invalid() {
+    for date in [
+        "2024-02-30",
+        "2024-13-01",
+        "2024-01",
+        "2024-01-01-01",
+        "today",
+        "-1-01-01",
+    ] {
+        assert!(parse_date(date).is_err(), "{}", date);
+    }
+}
+
+/// Affirm that everyone playing on the same day gets the same board, and that it differs from the
+/// next day's.
+#[test]
+fn shared() {
+    let today = Date::from_calendar_date(2026, Month::October, 18).unwrap();
+    assert_eq!(board(today).notation(), board(today).notation());
+    assert_ne!(
+        board(today).notation(),
+        board(today.next_day().unwrap()).notation()
+    );
+}
@@ -0,0 +1,90 @@
+//! Files kept between runs, such as the high-score table. Each is kept as JSON in the user's data
+//! directory (e.g. `~/.local/share/rust2048/`), unless a path is given on the command line.
+
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::args::Args;
+
+/// Returns the path of a file given by an option, or otherwise its path in the user's data
+/// directory.
+///
+/// # Arguments
+///
+/// * `args` - the parsed command line arguments
+/// * `option` - the name of the option which may give the path
+/// * `file` - the name of the file in the data directory
+pub fn path(args: &Args, option: &str, file: &str) -> Result<PathBuf, String> {
+    match args.get::<PathBuf>(option)? {
+        Some(path) => Ok(path),
+        None => dirs::data_dir()
+            .map(|dir| dir.join("rust2048").join(file))
+            .ok_or_else(|| format!("no data directory; use --{}", option)),
+    }
+}
+
+/// Loads a value from a file. A missing file holds the default value. A file which cannot be
+/// parsed is moved aside, to `<path>.corrupt`, so that it is not overwritten, and the default
+/// value is returned in its place.
+///
+/// # Arguments
+///
+/// * `path` - the path of the file
+pub fn load<T: DeserializeOwned + Default>(path: &Path) -> Result<T, String> {
+    let json = match std::fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(T::default()),
+        Err(e) => return Err(format!("{}: {}", path.display(), e)),
+    };
+
+    match serde_json::from_str(&json) {
+        Ok(value) => Ok(value),
+        Err(e) => {
+            let backup = sibling(path, ".corrupt");
+            std::fs::rename(path, &backup).map_err(|e| format!("{}: {}", path.display(), e))?;
+            eprintln!(
+                "warning: {} is corrupt ({}) and was moved to {}",
+                path.display(),
+                e,
+                backup.display()
+            );
+            Ok(T::default())
+        }
+    }
+}
+
+/// Saves a value to a file, creating its directory if needed. The value is written to a
+/// temporary file which then replaces the previous one, so that the file is never left partially
+/// written.
+///
+/// # Arguments
+///
+/// * `value` - the value to save
+/// * `path` - the path of the file
+pub fn save(value: &impl Serialize, path: &Path) -> Result<(), String> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+
+    let temp = sibling(path, ".tmp");
+    std::fs::write(&temp, serde_json::to_string_pretty(value).unwrap())
+        .and_then(|_| std::fs::rename(&temp, path))
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Returns the path of a file beside another, named by appending a suffix to its name.
+///
+/// # Arguments
+///
+/// * `path` - the path of the other file
+/// * `suffix` - the suffix to append
+pub fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,35 @@
+use super::*;
+
+/// Affirm that a saved value is loaded unchanged, leaving no temporary file behind.
+#[test]
+fn round_trip() {
+    let path = temp_path("round-trip");
+    save(&vec![1, 2, 3], &path).unwrap();
+
+    assert_eq!(vec![1, 2, 3], load::<Vec<u32>>(&path).unwrap());
+    assert!(!sibling(&path, ".tmp").exists());
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+/// Affirm that a missing file holds the default value.
+#[test]
+fn missing() {
+    let path = temp_path("missing");
+    assert_eq!(Vec::<u32>::new(), load::<Vec<u32>>(&path).unwrap());
+}
+
+/// Affirm that a corrupt file is moved aside and replaced by the default value.
+#[test]
+fn corrupt() {
+    let path = temp_path("corrupt");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "[1, 2").unwrap();
+
+    assert_eq!(Vec::<u32>::new(), load::<Vec<u32>>(&path).unwrap());
+    assert_eq!(
+        "[1, 2",
+        std::fs::read_to_string(sibling(&path, ".corrupt")).unwrap()
+    );
+    assert!(!path.exists());
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
@@ -0,0 +1,10 @@
+use super::*;
+
+mod load;
+
+/// Returns a path in a fresh temporary directory, unique to the test.
+fn temp_path(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rust2048-data-{}-{}", test, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir.join("data.json")
+}
@@ -1,29 +1,124 @@
+mod args;
+mod batch;
+mod blitz;
 mod board;
+mod bot;
 mod cell;
+mod challenge;
+mod coop;
+mod daily;
+mod data;
 mod movement;
+mod protocol;
+mod puzzle;
+mod render;
+mod replay;
+mod scores;
+mod server;
+mod stats;
+mod tournament;
+mod versus;
 
 use std::io::Write;
+use std::time::Instant;
 
-use board::Board;
+use board::{Board, Status};
 use cell::Cell;
 use movement::{Direction, Move};
 
-fn parse_input(inp: &str) -> Result<Move, ()> {
-    match inp {
-        "w" => Ok(Move::Shift(Direction::Up)),
-        "a" => Ok(Move::Shift(Direction::Left)),
-        "s" => Ok(Move::Shift(Direction::Down)),
-        "d" => Ok(Move::Shift(Direction::Right)),
-        "u" => Ok(Move::Undo),
-        _ => Err(()),
+/// Parses a movement from the key pressed by the player: `u` undoes, `w`, `a`, `s` and `d` shift
+/// the board up, left, down and right, `q`, `e`, `z` and `c` shift it towards the corner they lie
+/// towards on the keyboard, and `i` and `o` shift a cube in and out. A key is only accepted if
+/// the board can be shifted in its direction.
+///
+/// # Arguments
+///
+/// * `inp` - the key pressed
+/// * `directions` - the directions in which the board can be shifted
+fn parse_input(inp: &str, directions: &[Direction]) -> Result<Move, ()> {
+    let dir = match inp {
+        "u" => return Ok(Move::Undo),
+        "w" => Direction::Up,
+        "a" => Direction::Left,
+        "s" => Direction::Down,
+        "d" => Direction::Right,
+        "q" => Direction::UpLeft,
+        "e" => Direction::UpRight,
+        "z" => Direction::DownLeft,
+        "c" => Direction::DownRight,
+        "i" => Direction::In,
+        "o" => Direction::Out,
+        _ => return Err(()),
+    };
+    match directions.contains(&dir) {
+        true => Ok(Move::Shift(dir)),
+        false => Err(()),
     }
 }
 
-fn main() {
-    let mut board = Board::new();
+/// Plays a game interactively, reading one movement per line from stdin. At game over, the game
+/// is recorded in the high-score table and the best games played under the same rules are shown.
+/// The statistics of the game are recorded when it ends, or when it is abandoned after a move.
+/// Given `--adversary DEPTH`, cells are spawned wherever they leave the player worst off, searching
+/// that many shifts ahead; such games are kept out of the high-score table. Given `--replay PATH`,
+/// a replay of the game is saved there after every move, to be checked by the `verify` mode.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+fn play(args: &[String]) -> Result<(), String> {
+    let args = args::Args::parse(args)?;
+    if let Some(arg) = args.positional().first() {
+        return Err(format!("unknown mode: {}", arg));
+    }
+    let seed = args.get::<u64>("seed")?.unwrap_or_else(rand::random);
+    let name = match args.get::<String>("name")? {
+        Some(name) => name,
+        None => std::env::var("USER").unwrap_or_else(|_| "player".to_string()),
+    };
+    let adversary = args.get::<usize>("adversary")?;
+
+    let replay_path = args.get::<std::path::PathBuf>("replay")?;
+    if adversary.is_some() && replay_path.is_some() {
+        return Err("games against an adversary cannot be replayed from their seed".to_string());
+    }
+
+    let mut board = Board::with_rules(args.rules()?, seed);
+    if let Some(depth) = adversary {
+        board.set_spawner(board::Adversarial { depth });
+    }
+    let mut replay = replay::Replay::new(&name, &board, seed);
+    let mut won = false;
+    let mut moves = 0;
+    let mut game = stats::Game::default();
+    let start = Instant::now();
 
     loop {
         println!("{}\n", board);
+        println!("score: {}\n", board.score());
+
+        if board.status() == Status::Won && !won {
+            won = true;
+            println!("you win! keep going for a higher score\n");
+        }
+        if board.legal_moves().is_empty() {
+            println!("game over\n");
+            // the game is over, so failing to keep it is only worth a warning
+            if let Err(e) =
+                data::path(&args, "stats", "stats.json").and_then(|path| tally(&path, game, &board))
+            {
+                eprintln!("warning: the statistics were not recorded: {}", e);
+            }
+            if adversary.is_none() {
+                let entry = scores::Entry::new(&name, &board, moves, start.elapsed(), seed);
+                if let Err(e) =
+                    data::path(&args, "scores", "scores.json").and_then(|path| record(&path, entry))
+                {
+                    eprintln!("warning: the score was not recorded: {}", e);
+                }
+            }
+            return Ok(());
+        }
 
         let mut mov: Option<Move> = None;
         while mov.is_none() {
@@ -31,19 +126,105 @@ fn main() {
             std::io::stdout().flush().unwrap();
 
             let mut input = String::new();
-            std::io::stdin().read_line(&mut input).unwrap();
+            if std::io::stdin().read_line(&mut input).unwrap() == 0 {
+                // stdin was closed, so no more moves can be read
+                if moves > 0 {
+                    if let Err(e) = data::path(&args, "stats", "stats.json")
+                        .and_then(|path| tally(&path, game, &board))
+                    {
+                        eprintln!("warning: the statistics were not recorded: {}", e);
+                    }
+                }
+                return Ok(());
+            }
             input.truncate(1);
             let input = input.to_lowercase();
 
-            match parse_input(&input) {
+            match parse_input(&input, &board.rules().directions()) {
                 Ok(m) => mov = Some(m),
                 _ => println!("invalid input"),
             };
         }
 
-        if let Err(()) = board.movement(mov.unwrap()) {
-            println!("invalid move");
+        let mov = mov.unwrap();
+        match board.movement(mov) {
+            Ok(events) => {
+                moves += 1;
+                game.observe(mov, &events);
+                if let Some(path) = &replay_path {
+                    replay.record(mov, &events, &board);
+                    data::save(&replay, path)?;
+                }
+            }
+            Err(()) => println!("invalid move"),
         }
         println!();
     }
 }
+
+/// Records a finished game in the high-score table, announcing a new best score, and shows the
+/// best games played under the same rules.
+///
+/// # Arguments
+///
+/// * `path` - the path of the high-score table
+/// * `entry` - the finished game
+fn record(path: &std::path::Path, entry: scores::Entry) -> Result<(), String> {
+    let mut table = data::load::<scores::Table>(path)?;
+
+    let rules = entry.rules.clone();
+    let score = entry.score;
+    if table.record(entry) == 1 {
+        println!("new best score of {} for {}!\n", score, rules);
+    }
+    data::save(&table, path)?;
+
+    println!("{}", scores::to_table(&table.top(&rules, 10)));
+    Ok(())
+}
+
+/// Records the statistics of a game which has ended.
+///
+/// # Arguments
+///
+/// * `path` - the path of the statistics of every game
+/// * `game` - the statistics tallied during the game
+/// * `board` - the final board of the game
+fn tally(path: &std::path::Path, mut game: stats::Game, board: &Board) -> Result<(), String> {
+    let mut history = data::load::<stats::History>(path)?;
+    game.finish(board);
+    history.games.push(game);
+    data::save(&history, path)
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+
+    let result = match args.first().map(String::as_str) {
+        Some("play") => play(&args[1..]),
+        Some("puzzle") => puzzle::run(&args[1..]),
+        Some("protocol") => {
+            protocol::engine(std::io::stdin().lock(), std::io::stdout()).map_err(|e| e.to_string())
+        }
+        Some("batch") => batch::run(&args[1..]),
+        Some("blitz") => blitz::run(&args[1..]),
+        Some("challenge") => challenge::run(&args[1..]),
+        Some("coop") => coop::run(&args[1..]),
+        Some("daily") => daily::run(&args[1..]),
+        Some("export") => render::export(&args[1..]),
+        Some("host") => protocol::host::run(&args[1..]),
+        Some("scores") => scores::run(&args[1..]),
+        Some("serve") => server::run(&args[1..]),
+        Some("snapshot") => render::snapshot(&args[1..]),
+        Some("stats") => stats::run(&args[1..]),
+        Some("tournament") => tournament::run(&args[1..]),
+        Some("verify") => replay::run(&args[1..]),
+        Some("versus") => versus::run(&args[1..]),
+        _ => play(&args),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
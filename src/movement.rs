@@ -1,14 +1,108 @@
-#[derive(PartialEq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Direction {
     Down,
     Left,
     Right,
     Up,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+    In,
+    Out,
+}
+
+/// Implementation of the `Display` trait for `Direction`.
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Direction::Down => "down",
+                Direction::Left => "left",
+                Direction::Right => "right",
+                Direction::Up => "up",
+                Direction::UpLeft => "upleft",
+                Direction::UpRight => "upright",
+                Direction::DownLeft => "downleft",
+                Direction::DownRight => "downright",
+                Direction::In => "in",
+                Direction::Out => "out",
+            }
+        )
+    }
 }
 
-#[derive(PartialEq)]
+/// Implementation of the `FromStr` trait for `Direction`.
+impl std::str::FromStr for Direction {
+    type Err = ();
+
+    /// Parses a direction from its lowercase name, as written by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Direction::ALL
+            .into_iter()
+            .chain(Direction::DIAGONALS)
+            .chain(Direction::DEPTH)
+            .find(|dir| dir.to_string() == s)
+            .ok_or(())
+    }
+}
+
+impl Direction {
+    /// Every direction in which a flat board can be shifted, other than the diagonals.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// The diagonal directions, in which the board can be shifted only if the rules allow it.
+    pub const DIAGONALS: [Direction; 4] = [
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+
+    /// The directions running through the layers of a cube, in which only a cube can be shifted:
+    /// in, away from the front layer, and out, towards it.
+    pub const DEPTH: [Direction; 2] = [Direction::In, Direction::Out];
+
+    /// Returns whether shifting in the direction moves cells towards the end of their groups,
+    /// whose cells are listed by increasing row (or by increasing column, for rows, or by
+    /// increasing layer, for the lines running through the layers of a cube).
+    pub fn reversed(self) -> bool {
+        matches!(
+            self,
+            Direction::Down
+                | Direction::Right
+                | Direction::DownLeft
+                | Direction::DownRight
+                | Direction::In
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 /// The representation of each of game movement.
 pub enum Move {
     Shift(Direction),
     Undo,
 }
+
+/// Implementation of the `Display` trait for `Move`.
+impl std::fmt::Display for Move {
+    /// Writes a shift as the name of its direction, and an undo as `undo`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Move::Shift(dir) => write!(f, "{}", dir),
+            Move::Undo => write!(f, "undo"),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command as Process, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use super::Command;
+use crate::board::{Board, Event};
+use crate::{Direction, Move};
+
+/// Defines how long a bot may take to answer the `p2048` handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Defines the time allowed on top of the move time for a reply to reach the host.
+const GRACE: Duration = Duration::from_millis(50);
+
+/// The representation of the reason a refereed game ended.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Ending {
+    /// No shift could be performed on the board.
+    GameOver,
+    /// The bot replied `bestmove none` while a shift could still be performed.
+    Resigned,
+    /// The bot did not reply within the time limit.
+    Timeout,
+    /// The bot chose a direction in which the board cannot be shifted.
+    IllegalMove(Direction),
+    /// The bot replied with a line that is not part of the protocol.
+    Malformed(String),
+    /// The bot exited or closed its output.
+    Disconnected,
+}
+
+/// The representation of the outcome of a refereed game.
+pub struct Report {
+    /// The board as it was when the game ended.
+    pub board: Board,
+    /// The number of moves that were played.
+    pub moves: usize,
+    /// The time the bot took to reply with each of its moves.
+    pub times: Vec<Duration>,
+    /// The reason the game ended.
+    pub ending: Ending,
+}
+
+/// The representation of an external bot process spoken to over its stdin and stdout.
+pub struct Bot {
+    /// The name the bot identified itself by, or the program name if it did not.
+    pub name: String,
+    /// The running bot process.
+    child: Child,
+    /// The input of the bot process.
+    stdin: ChildStdin,
+    /// The lines written by the bot process, read on a separate thread.
+    lines: Receiver<String>,
+}
+
+/// Implementation of the `Drop` trait for `Bot`.
+impl Drop for Bot {
+    /// Kills the bot process, in case it did not exit on `quit`.
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+impl Bot {
+    /// Launches a bot process and performs the protocol handshake with it.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - the program to run
+    /// * `args` - the arguments to pass to the program
+    pub fn launch(program: &str, args: &[String]) -> Result<Self, String> {
+        let mut child = Process::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("failed to launch `{}`: {}", program, e))?;
+
+        let stdin = child.stdin.take().unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let (sender, lines) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in stdout.lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut bot = Self {
+            name: program.to_string(),
+            child,
+            stdin,
+            lines,
+        };
+
+        bot.send(&Command::Hello)
+            .map_err(|_| "failed to send handshake")?;
+        loop {
+            match bot
+                .receive(HANDSHAKE_TIMEOUT)
+                .map_err(|e| format!("handshake: {:?}", e))?
+            {
+                line if line == "p2048ok" => break,
+                line => {
+                    if let Some(name) = line.strip_prefix("id name ") {
+                        bot.name = name.to_string();
+                    }
+                }
+            }
+        }
+        Ok(bot)
+    }
+
+    /// Sends a command to the bot.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - the command to send
+    fn send(&mut self, cmd: &Command) -> Result<(), Ending> {
+        writeln!(self.stdin, "{}", cmd)
+            .and_then(|_| self.stdin.flush())
+            .map_err(|_| Ending::Disconnected)
+    }
+
+    /// Waits for the next line written by the bot.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - the longest time to wait for
+    fn receive(&mut self, timeout: Duration) -> Result<String, Ending> {
+        self.lines.recv_timeout(timeout).map_err(|e| match e {
+            RecvTimeoutError::Timeout => Ending::Timeout,
+            RecvTimeoutError::Disconnected => Ending::Disconnected,
+        })
+    }
+
+    /// Asks the bot for its move, ignoring any lines other than `bestmove`.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - the time the bot has to reply
+    fn go(&mut self, limit: Duration) -> Result<Option<Direction>, Ending> {
+        self.send(&Command::Go(Some(limit)))?;
+
+        let deadline = Instant::now() + limit + GRACE;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let line = self.receive(remaining)?;
+            if let Some(dir) = line.strip_prefix("bestmove ") {
+                return match dir.trim() {
+                    "none" => Ok(None),
+                    dir => dir
+                        .parse()
+                        .map(Some)
+                        .map_err(|_| Ending::Malformed(line.clone())),
+                };
+            }
+        }
+    }
+
+    /// Plays moves on the board until no shift can be performed, relaying every move and spawn
+    /// back to the bot.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the board to play on
+    /// * `limit` - the time the bot has to reply with each move
+    /// * `times` - the record of the time the bot took to reply with each move
+    fn play(
+        &mut self,
+        board: &mut Board,
+        limit: Duration,
+        times: &mut Vec<Duration>,
+    ) -> Result<(), Ending> {
+        self.send(&Command::NewGame)?;
+        self.send(&Command::Position(board.notation()))?;
+
+        while !board.legal_moves().is_empty() {
+            let start = Instant::now();
+            let dir = self.go(limit)?.ok_or(Ending::Resigned)?;
+            let elapsed = start.elapsed();
+
+            let events = board
+                .movement(Move::Shift(dir))
+                .map_err(|_| Ending::IllegalMove(dir))?;
+            times.push(elapsed);
+
+            self.send(&Command::Move(dir))?;
+            for event in events {
+                if let Event::Spawn { at, cell } = event {
+                    self.send(&Command::Spawn(at, cell.value().clone()))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Plays a game with the bot from the specified board until it ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the board to begin the game from
+    /// * `limit` - the time the bot has to reply with each move
+    pub fn referee(&mut self, mut board: Board, limit: Duration) -> Report {
+        let mut times = Vec::new();
+        let ending = match self.play(&mut board, limit, &mut times) {
+            Ok(()) => Ending::GameOver,
+            Err(ending) => ending,
+        };
+
+        let _ = self.send(&Command::Quit);
+        Report {
+            board,
+            moves: times.len(),
+            times,
+            ending,
+        }
+    }
+}
+
+/// Runs the `host` mode: launches a bot and referees a single game with it.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let seed = args.get::<u64>("seed")?.unwrap_or_else(rand::random);
+    let limit = Duration::from_millis(args.get::<u64>("movetime")?.unwrap_or(1000));
+    let (program, program_args) = args
+        .positional()
+        .split_first()
+        .ok_or("usage: host [--seed N] [--movetime MS] <program> [args...]")?;
+
+    let mut bot = Bot::launch(program, program_args)?;
+    let report = bot.referee(Board::with_seed(seed), limit);
+
+    println!("{}\n", report.board);
+    println!("bot: {}", bot.name);
+    println!("seed: {}", seed);
+    println!("score: {}", report.board.score());
+    println!("moves: {}", report.moves);
+    if !report.times.is_empty() {
+        let total = report.times.iter().sum::<Duration>();
+        println!("time per move: {:?}", total / report.times.len() as u32);
+    }
+    println!("ending: {:?}", report.ending);
+    Ok(())
+}
@@ -0,0 +1,173 @@
+//! A line-based text protocol, in the style of UCI, spoken between a host that referees a game
+//! and a bot that plays it. Every message is a single line of whitespace-separated words.
+//!
+//! # Host to bot
+//!
+//! * `p2048` - sent once after the bot starts; the bot replies with `id name <name>` (optional)
+//!   followed by `p2048ok`
+//! * `isready` - the bot replies with `readyok` once it has processed every prior command
+//! * `newgame` - the following commands concern a new game
+//! * `position <notation>` - sets the bot's board to the grid written in board notation
+//! * `move <direction>` - shifts the bot's board in the direction, without spawning a cell
+//! * `spawn <row> <column> <value>` - places a cell of the value at the zero-indexed position; the
+//!   value is written in full, however large
+//! * `go [movetime <milliseconds>]` - asks the bot for a move, to be made within the time limit
+//! * `quit` - the bot should exit
+//!
+//! # Bot to host
+//!
+//! * `id name <name>` - the name of the bot
+//! * `p2048ok` - the bot is ready to receive commands
+//! * `readyok` - reply to `isready`
+//! * `bestmove <direction>` - reply to `go`, where `<direction>` is one of `up`, `down`, `left`
+//!   or `right`
+//! * `bestmove none` - reply to `go` when no shift can be performed, i.e. the game is over; a
+//!   host receiving it while a shift is still possible treats the bot as having resigned
+//!
+//! After each move the host sends the `move` that was played, followed by a `spawn` for the cell
+//! that appeared, so a bot may track the game without being sent the full `position` again.
+//! Unknown commands are ignored by both sides.
+
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::board::{Board, BoardCoord};
+use crate::cell::Value;
+use crate::{bot, Cell, Direction};
+
+pub mod host;
+
+/// Defines the name a bot built into this program identifies itself by.
+const ENGINE_NAME: &str = "rust2048";
+
+/// The representation of each message sent from the host to a bot.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Hello,
+    IsReady,
+    NewGame,
+    Position(String),
+    Move(Direction),
+    Spawn(BoardCoord, Value),
+    Go(Option<Duration>),
+    Quit,
+}
+
+/// Implementation of the `Display` trait for `Command`.
+impl std::fmt::Display for Command {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Command::Hello => write!(f, "p2048"),
+            Command::IsReady => write!(f, "isready"),
+            Command::NewGame => write!(f, "newgame"),
+            Command::Position(notation) => write!(f, "position {}", notation),
+            Command::Move(dir) => write!(f, "move {}", dir),
+            Command::Spawn((row, col), value) => write!(f, "spawn {} {} {}", row, col, value),
+            Command::Go(None) => write!(f, "go"),
+            Command::Go(Some(limit)) => write!(f, "go movetime {}", limit.as_millis()),
+            Command::Quit => write!(f, "quit"),
+        }
+    }
+}
+
+/// Implementation of the `FromStr` trait for `Command`.
+impl FromStr for Command {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s.split_whitespace().collect::<Vec<&str>>();
+        let num = |word: &str| word.parse::<usize>().map_err(|_| ());
+
+        match words.as_slice() {
+            ["p2048"] => Ok(Command::Hello),
+            ["isready"] => Ok(Command::IsReady),
+            ["newgame"] => Ok(Command::NewGame),
+            ["position", notation] => Ok(Command::Position(notation.to_string())),
+            ["move", dir] => Ok(Command::Move(dir.parse()?)),
+            ["spawn", row, col, value] => Ok(Command::Spawn(
+                (num(row)?, num(col)?),
+                value.parse::<Value>().map_err(|_| ())?,
+            )),
+            ["go"] => Ok(Command::Go(None)),
+            ["go", "movetime", ms] => Ok(Command::Go(Some(Duration::from_millis(
+                ms.parse::<u64>().map_err(|_| ())?,
+            )))),
+            ["quit"] => Ok(Command::Quit),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Applies a command to the board tracked by a bot, returning the reply (if any) to send back to
+/// the host. Commands which cannot be applied to the board are ignored.
+///
+/// # Arguments
+///
+/// * `board` - the board tracked by the bot
+/// * `cmd` - the command to apply
+fn respond(board: &mut Option<Board>, cmd: Command) -> Option<String> {
+    match cmd {
+        Command::Hello => Some(format!("id name {}\np2048ok", ENGINE_NAME)),
+        Command::IsReady => Some("readyok".to_string()),
+        Command::NewGame => {
+            *board = None;
+            None
+        }
+        Command::Position(notation) => {
+            *board = notation.parse().ok();
+            None
+        }
+        Command::Move(dir) => {
+            if let Some(next) = board.as_ref().and_then(|b| b.shifted(dir)) {
+                *board = Some(next);
+            }
+            None
+        }
+        Command::Spawn(pos, value) => {
+            if let Some(b) = board.as_mut() {
+                if let Some(cell) = Cell::with_value(value, b.rules().variant.rule()) {
+                    let _ = b.place(pos, cell);
+                }
+            }
+            None
+        }
+        Command::Go(_) => Some(format!(
+            "bestmove {}",
+            board
+                .as_ref()
+                .and_then(bot::best_move)
+                .map_or("none".to_string(), |dir| dir.to_string())
+        )),
+        Command::Quit => None,
+    }
+}
+
+/// Runs the built-in bot, reading commands from `input` and writing replies to `output` until
+/// `quit` is received or the input is exhausted.
+///
+/// # Arguments
+///
+/// * `input` - the stream of commands sent by the host
+/// * `output` - the stream to write replies to
+pub fn engine(input: impl BufRead, mut output: impl Write) -> std::io::Result<()> {
+    let mut board: Option<Board> = None;
+
+    for line in input.lines() {
+        let cmd = match line?.parse::<Command>() {
+            Ok(cmd) => cmd,
+            Err(()) => continue,
+        };
+        if cmd == Command::Quit {
+            break;
+        }
+        if let Some(reply) = respond(&mut board, cmd) {
+            writeln!(output, "{}", reply)?;
+            output.flush()?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,32 @@
+use super::*;
+
+/// Affirm that every command is parsed back from the line it is written as.
+#[test]
+fn round_trip() {
+    let commands = vec![
+        Command::Hello,
+        Command::IsReady,
+        Command::NewGame,
+        Command::Position("2,0,0,0/0,0,0,0/0,0,0,0/0,0,0,4".to_string()),
+        Command::Move(Direction::Left),
+        Command::Spawn((1, 3), Value::from(4u8)),
+        // a value too large for a `usize` is carried in full
+        Command::Spawn((0, 0), Value::from(2u8).pow(100)),
+        Command::Go(None),
+        Command::Go(Some(Duration::from_millis(250))),
+        Command::Quit,
+    ];
+    for cmd in commands {
+        assert_eq!(Ok(&cmd), cmd.to_string().parse::<Command>().as_ref());
+    }
+}
+
+/// Affirm that unknown commands and malformed arguments are rejected.
+#[test]
+fn invalid() {
+    assert!("uci".parse::<Command>().is_err());
+    assert!("move sideways".parse::<Command>().is_err());
+    assert!("spawn 1 two 4".parse::<Command>().is_err());
+    assert!("spawn 1 2 -4".parse::<Command>().is_err());
+    assert!("go movetime".parse::<Command>().is_err());
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+/// Runs the built-in bot over the specified input, returning everything it wrote.
+fn run(input: &str) -> String {
+    let mut output = Vec::new();
+    engine(input.as_bytes(), &mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+/// Affirm that the bot answers the handshake and readiness check.
+#[test]
+fn handshake() {
+    assert_eq!(
+        "id name rust2048\np2048ok\nreadyok\n",
+        run("p2048\nisready\n")
+    );
+}
+
+/// Affirm that the bot chooses a move for the position it was sent.
+#[test]
+fn go_after_position() {
+    let input = "newgame\nposition 2,2,0,0/4,0,0,0/0,0,0,0/0,0,0,0\ngo movetime 100\n";
+    assert_eq!("bestmove left\n", run(input));
+}
+
+/// Affirm that the bot tracks the moves and spawns relayed by the host.
+#[test]
+fn go_after_move_and_spawn() {
+    let input = "position 2,4,2,4/4,2,4,2/2,4,2,4/8,16,8,0\n\
+                 move right\n\
+                 spawn 3 0 16\n\
+                 go\n";
+    assert_eq!("bestmove none\n", run(input));
+}
+
+/// Affirm that the bot places a spawned cell whose value is too large for a `usize` as it was sent.
+#[test]
+fn spawn_large_value() {
+    let mut board = "0,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().ok();
+    let value = Value::from(2u8).pow(100);
+    let cmd = format!("spawn 0 1 {}", value).parse::<Command>().unwrap();
+    assert_eq!(None, respond(&mut board, cmd));
+
+    let cells = board.unwrap().cells();
+    assert_eq!(Some(&value), cells[0][1].as_ref().map(Cell::value));
+}
+
+/// Affirm that the bot stops reading commands once told to quit.
+#[test]
+fn quit() {
+    assert_eq!("", run("quit\nisready\n"));
+}
+
+/// Affirm that the bot resigns when asked for a move without a position.
+#[test]
+fn go_without_position() {
+    assert_eq!("bestmove none\n", run("go\n"));
+}
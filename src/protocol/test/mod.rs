@@ -0,0 +1,4 @@
+use super::*;
+
+mod command;
+mod engine;
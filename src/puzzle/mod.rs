@@ -0,0 +1,297 @@
+use std::collections::{HashSet, VecDeque};
+use std::io::Write;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::board::{Board, BoardCoord, Rules, Scripted};
+use crate::challenge::Goal;
+use crate::{parse_input, Cell, Direction, Move};
+
+/// The representation of a cell spawned after a shift of a puzzle.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Spawn {
+    /// The value of the spawned cell.
+    pub value: usize,
+    /// The coordinate at which the cell is spawned. The cell is spawned at the first empty cell
+    /// of the board, row by row, if no coordinate is given or the coordinate is occupied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<BoardCoord>,
+}
+
+/// The definition of a puzzle: a hand-authored position, the cells spawned after each shift and
+/// a goal to be met within a fixed number of moves. Puzzles are written as JSON, e.g.
+/// `{"name": "pair", "start": "2,2,0/0,0,0/0,0,0", "spawns": [{"value": 2, "at": [2, 2]}],
+/// "goal": {"tile": 8}, "moves": 3}`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Puzzle {
+    /// The name the puzzle is known by.
+    #[serde(default)]
+    pub name: String,
+    /// The rules the puzzle is played by; if none are given, the default rules sized to fit the
+    /// starting position and walled as it is are taken.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Rules>,
+    /// The notation of the starting position.
+    pub start: String,
+    /// The cells spawned after each shift, in order. Nothing is spawned once they run out.
+    #[serde(default)]
+    pub spawns: Vec<Spawn>,
+    /// The goal of the puzzle.
+    pub goal: Goal,
+    /// The number of shifts within which the goal must be met.
+    pub moves: usize,
+}
+
+/// The representation of a pack of puzzles, as published in a single file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Pack {
+    /// The name of the pack.
+    #[serde(default)]
+    pub name: String,
+    /// The puzzles of the pack, in the order they are meant to be played.
+    pub puzzles: Vec<Puzzle>,
+}
+
+impl Pack {
+    /// Loads a pack of puzzles from a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - the path of the file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+impl Puzzle {
+    /// Returns the board the puzzle starts from, which spawns the puzzle's cells after each shift,
+    /// checking that every cell it spawns holds a value allowed by its rules.
+    pub fn board(&self) -> Result<Board, String> {
+        let invalid = |_| format!("invalid start: {}", self.start);
+        let mut board = match &self.rules {
+            Some(rules) => Board::with_position(rules.clone(), &self.start, 0).map_err(invalid)?,
+            None => self.start.parse::<Board>().map_err(invalid)?,
+        };
+        let rule = board.rules().variant.rule();
+        let spawns = self
+            .spawns
+            .iter()
+            .map(|spawn| match Cell::of(spawn.value, rule) {
+                Some(cell) => Ok((cell, spawn.at)),
+                None => Err(format!("invalid spawn: {}", spawn.value)),
+            })
+            .collect::<Result<Vec<(Cell, Option<BoardCoord>)>, String>>()?;
+        board.set_spawner(Scripted::new(spawns));
+        Ok(board)
+    }
+
+    /// Finds the shortest sequence of shifts which solves the puzzle within its moves, or `None`
+    /// if it cannot be solved. Every position reachable within the moves is searched breadth
+    /// first, so the sequence found is no longer than any other solution.
+    pub fn solve(&self) -> Result<Option<Vec<Direction>>, String> {
+        let start = Session::new(self)?;
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::from([(start, Vec::new())]);
+
+        while let Some((session, path)) = queue.pop_front() {
+            if session.is_solved() {
+                return Ok(Some(path));
+            }
+            if session.moves_left() == 0 {
+                continue;
+            }
+            for dir in session.board.legal_moves() {
+                let mut next = session.clone();
+                next.play(dir).unwrap();
+                // positions reached with the same spawns left play out identically
+                if seen.insert((next.board.notation(), next.spawned())) {
+                    let mut path = path.clone();
+                    path.push(dir);
+                    queue.push_back((next, path));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The representation of an attempt at a puzzle in progress.
+#[derive(Clone)]
+pub struct Session<'a> {
+    /// The puzzle being attempted.
+    puzzle: &'a Puzzle,
+    /// The board of the attempt.
+    board: Board,
+    /// The number of shifts made.
+    moves: usize,
+}
+
+impl<'a> Session<'a> {
+    /// Begins an attempt at a puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - the puzzle to attempt
+    pub fn new(puzzle: &'a Puzzle) -> Result<Self, String> {
+        Ok(Self {
+            board: puzzle.board()?,
+            puzzle,
+            moves: 0,
+        })
+    }
+
+    /// Returns the board of the attempt.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Returns the number of shifts left.
+    pub fn moves_left(&self) -> usize {
+        self.puzzle.moves.saturating_sub(self.moves)
+    }
+
+    /// Returns the number of the puzzle's cells spawned (or lost to a full board) so far, one
+    /// after each shift until they run out.
+    fn spawned(&self) -> usize {
+        self.moves.min(self.puzzle.spawns.len())
+    }
+
+    /// Returns whether the puzzle has been solved.
+    pub fn is_solved(&self) -> bool {
+        self.puzzle.goal.is_met(&self.board)
+    }
+
+    /// Returns whether the attempt has ended, i.e. the puzzle has been solved, no shifts are left
+    /// or the board can no longer be shifted.
+    pub fn is_over(&self) -> bool {
+        self.is_solved() || self.moves_left() == 0 || self.board.legal_moves().is_empty()
+    }
+
+    /// Shifts the board, then spawns the next of the puzzle's cells, if any are left. Fails if the
+    /// attempt has ended or the board cannot be shifted in the direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - the direction in which to shift
+    pub fn play(&mut self, dir: Direction) -> Result<(), ()> {
+        if self.is_over() {
+            return Err(());
+        }
+        self.board.movement(Move::Shift(dir))?;
+        self.moves += 1;
+        Ok(())
+    }
+}
+
+/// Runs the `puzzle` mode. Given `--solve true`, every puzzle of the pack given as a positional
+/// argument is solved, printing the shortest solution of each, and the mode fails if any has no
+/// solution within its moves. Otherwise, the puzzle numbered by the second positional argument
+/// (counting from one, the first by default) is played interactively, reading one movement per
+/// line from stdin, and whether it was solved is shown once the attempt ends.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let usage = "usage: puzzle [--solve true] PACK [NUMBER]";
+    let file = args.positional().first().ok_or(usage)?;
+    let pack = Pack::load(Path::new(file))?;
+
+    if args.get::<bool>("solve")?.unwrap_or(false) {
+        return solve(&pack);
+    }
+
+    let number = match args.positional().get(1) {
+        Some(number) => number.parse::<usize>().map_err(|_| usage.to_string())?,
+        None => 1,
+    };
+    let puzzle = number
+        .checked_sub(1)
+        .and_then(|idx| pack.puzzles.get(idx))
+        .ok_or_else(|| format!("no puzzle numbered {} in {}", number, file))?;
+
+    println!("{}\n", puzzle.name);
+    println!("goal: {} in {} moves\n", puzzle.goal, puzzle.moves);
+    let mut session = Session::new(puzzle)?;
+
+    while !session.is_over() {
+        println!("{}\n", session.board());
+        println!("moves left: {}\n", session.moves_left());
+
+        print!("move: ");
+        std::io::stdout().flush().unwrap();
+        let mut input = String::new();
+        if std::io::stdin().read_line(&mut input).unwrap() == 0 {
+            // stdin was closed, so the attempt is abandoned
+            return Ok(());
+        }
+        input.truncate(1);
+        let input = input.to_lowercase();
+
+        match parse_input(&input, &session.board().rules().directions()) {
+            Ok(Move::Shift(dir)) => {
+                if session.play(dir).is_err() {
+                    println!("invalid move");
+                }
+            }
+            Ok(Move::Undo) => println!("puzzles cannot be undone"),
+            Err(()) => println!("invalid input"),
+        }
+        println!();
+    }
+
+    println!("{}\n", session.board());
+    match session.is_solved() {
+        true => println!("solved in {} moves", session.moves),
+        false => println!("not solved"),
+    }
+    Ok(())
+}
+
+/// Solves every puzzle of a pack, printing the shortest solution of each, and fails if any has
+/// no solution within its moves.
+///
+/// # Arguments
+///
+/// * `pack` - the pack of puzzles to solve
+fn solve(pack: &Pack) -> Result<(), String> {
+    let mut unsolved = 0;
+    for (idx, puzzle) in pack.puzzles.iter().enumerate() {
+        match puzzle.solve()? {
+            Some(path) => println!(
+                "{} {}: solved in {} moves: {}",
+                idx + 1,
+                puzzle.name,
+                path.len(),
+                path.iter()
+                    .map(Direction::to_string)
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
+            None => {
+                unsolved += 1;
+                println!(
+                    "{} {}: no solution within {} moves",
+                    idx + 1,
+                    puzzle.name,
+                    puzzle.moves
+                );
+            }
+        }
+    }
+    match unsolved {
+        0 => Ok(()),
+        n => Err(format!(
+            "{} of {} puzzles have no solution",
+            n,
+            pack.puzzles.len()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,25 @@
+use super::*;
+
+/// Affirm that a puzzle without rules is sized to fit its starting position.
+#[test]
+fn sized() {
+    let board = puzzle("2,0,0/0,#,0", &[], Goal::Tile(8), 3)
+        .board()
+        .unwrap();
+    assert_eq!(2, board.rules().rows);
+    assert_eq!(3, board.rules().cols);
+    assert_eq!(vec![(1, 1)], board.rules().walls);
+}
+
+/// Affirm that a starting position which does not fit the rules, or a spawned value which they
+/// do not allow, is rejected.
+#[test]
+fn invalid() {
+    let mut invalid = puzzle("2,0/0,0", &[], Goal::Tile(8), 3);
+    invalid.rules = Some(Rules::default());
+    assert!(invalid.board().is_err());
+
+    let mut invalid = puzzle("2,0/0,0", &[], Goal::Tile(8), 3);
+    invalid.spawns.push(Spawn { value: 3, at: None });
+    assert!(invalid.board().is_err());
+}
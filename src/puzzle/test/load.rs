@@ -0,0 +1,23 @@
+use super::*;
+
+/// Affirm that a pack of puzzles is read from its JSON definition.
+#[test]
+fn pack() {
+    let path = std::env::temp_dir().join(format!("rust2048-pack-{}.json", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"{"name": "starter", "puzzles": [
+            {"start": "2,2/0,0", "goal": {"tile": 4}, "moves": 1},
+            {"start": "2,0/2,0", "spawns": [{"value": 4}], "goal": "single", "moves": 2}
+        ]}"#,
+    )
+    .unwrap();
+    let loaded = Pack::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let pack = loaded.unwrap();
+    assert_eq!("starter", pack.name);
+    assert_eq!(2, pack.puzzles.len());
+    assert_eq!(Goal::Single, pack.puzzles[1].goal);
+    assert_eq!(vec![Spawn { value: 4, at: None }], pack.puzzles[1].spawns);
+}
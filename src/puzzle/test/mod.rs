@@ -0,0 +1,25 @@
+use super::*;
+
+mod board;
+mod load;
+mod play;
+mod solve;
+
+/// Returns a puzzle of the specified goal and moves, starting from the position written and
+/// spawning a two at each of the coordinates given, in order.
+fn puzzle(start: &str, spawns: &[BoardCoord], goal: Goal, moves: usize) -> Puzzle {
+    Puzzle {
+        name: "test".to_string(),
+        rules: None,
+        start: start.to_string(),
+        spawns: spawns
+            .iter()
+            .map(|at| Spawn {
+                value: 2,
+                at: Some(*at),
+            })
+            .collect(),
+        goal,
+        moves,
+    }
+}
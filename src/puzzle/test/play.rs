@@ -0,0 +1,35 @@
+use super::*;
+
+/// Affirm that each shift spawns the next of the puzzle's cells where it is written, or at the
+/// first empty cell if that is occupied, and that nothing is spawned once they run out.
+#[test]
+fn spawns() {
+    let puzzle = puzzle("2,0,0/0,0,0/0,0,0", &[(2, 2), (2, 0)], Goal::Tile(64), 5);
+    let mut session = Session::new(&puzzle).unwrap();
+
+    session.play(Direction::Right).unwrap();
+    assert_eq!("0,0,2/0,0,0/0,0,2", session.board().notation());
+    session.play(Direction::Left).unwrap();
+    assert_eq!("2,2,0/0,0,0/2,0,0", session.board().notation());
+    session.play(Direction::Left).unwrap();
+    assert_eq!("4,0,0/0,0,0/2,0,0", session.board().notation());
+    assert_eq!(2, session.moves_left());
+}
+
+/// Affirm that an attempt ends once the puzzle is solved or its moves run out, and cannot be
+/// played further.
+#[test]
+fn ends() {
+    let solvable = puzzle("2,2/0,0", &[], Goal::Tile(4), 2);
+    let mut session = Session::new(&solvable).unwrap();
+    session.play(Direction::Left).unwrap();
+    assert!(session.is_solved());
+    assert!(session.is_over());
+    session.play(Direction::Down).unwrap_err();
+
+    let unsolvable = puzzle("2,4/0,0", &[], Goal::Tile(8), 1);
+    let mut session = Session::new(&unsolvable).unwrap();
+    session.play(Direction::Down).unwrap();
+    assert!(!session.is_solved());
+    assert!(session.is_over());
+}
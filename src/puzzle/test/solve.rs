@@ -0,0 +1,34 @@
+use super::*;
+
+/// Affirm that the shortest solution is found, and that a puzzle is unsolvable if it needs more
+/// moves than it allows.
+#[test]
+fn shortest() {
+    let puzzle = |moves| super::puzzle("2,2,4/0,0,0/0,0,0", &[], Goal::Tile(8), moves);
+    assert_eq!(
+        Some(vec![Direction::Left, Direction::Left]),
+        puzzle(5).solve().unwrap()
+    );
+    assert_eq!(None, puzzle(1).solve().unwrap());
+}
+
+/// Affirm that the solver plays out the puzzle's spawns.
+#[test]
+fn spawns() {
+    let puzzle = puzzle("2,0,0/0,0,0/0,0,0", &[(0, 2)], Goal::Tile(4), 2);
+    assert_eq!(
+        Some(vec![Direction::Right, Direction::Left]),
+        puzzle.solve().unwrap()
+    );
+}
+
+/// Affirm that a puzzle whose goal is to clear the board down to one tile is solved once a
+/// single cell is left, and that a solved starting position needs no moves.
+#[test]
+fn single() {
+    let puzzle = puzzle("2,0/2,0", &[], Goal::Single, 3);
+    assert_eq!(Some(vec![Direction::Up]), puzzle.solve().unwrap());
+
+    let solved = super::puzzle("0,0/8,0", &[], Goal::Single, 3);
+    assert_eq!(Some(Vec::new()), solved.solve().unwrap());
+}
@@ -0,0 +1,394 @@
+//! Rendering of replays for spectators: every position of a replayed game as text, as a series of
+//! SVG frames, as an animated SVG or as an animated GIF, and a single position as an SVG or PNG
+//! snapshot. Frames are drawn on the CPU alone, from the steps of the replay and the events each
+//! movement produces, so that merged and spawned tiles can be marked.
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::board::{Board, Event};
+use crate::cell::{Special, Value};
+use crate::replay::Replay;
+
+mod raster;
+mod svg;
+
+pub use raster::{gif, png};
+pub use svg::{animated, snapshot as svg_snapshot};
+
+/// A colour, as its red, green and blue components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Colour(pub u8, pub u8, pub u8);
+
+/// Implementation of the `Display` trait for `Colour`.
+impl std::fmt::Display for Colour {
+    /// Writes the colour in hexadecimal notation, e.g. `#eee4da`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// The colour of the page around the board.
+pub const PAGE: Colour = Colour(0xfa, 0xf8, 0xef);
+
+/// The colour of the board between its tiles.
+pub const BOARD: Colour = Colour(0xbb, 0xad, 0xa0);
+
+/// The colour of an empty tile.
+pub const EMPTY: Colour = Colour(0xcd, 0xc1, 0xb4);
+
+/// The colour of a wall, and of dark text.
+pub const DARK: Colour = Colour(0x77, 0x6e, 0x65);
+
+/// The colour of light text.
+pub const LIGHT: Colour = Colour(0xf9, 0xf6, 0xf2);
+
+/// The colour of the mark around a tile formed or spawned by the last movement.
+pub const MARK: Colour = Colour(0x8f, 0x7a, 0x66);
+
+/// The colours of tiles holding values, from the smallest value spawned upward, each value twice
+/// the one before it (or more) taking the next colour. Larger values take the last colour.
+const TILES: [Colour; 12] = [
+    Colour(0xee, 0xe4, 0xda),
+    Colour(0xed, 0xe0, 0xc8),
+    Colour(0xf2, 0xb1, 0x79),
+    Colour(0xf5, 0x95, 0x63),
+    Colour(0xf6, 0x7c, 0x5f),
+    Colour(0xf6, 0x5e, 0x3b),
+    Colour(0xed, 0xcf, 0x72),
+    Colour(0xed, 0xcc, 0x61),
+    Colour(0xed, 0xc8, 0x50),
+    Colour(0xed, 0xc5, 0x3f),
+    Colour(0xed, 0xc2, 0x2e),
+    Colour(0x3c, 0x3a, 0x32),
+];
+
+/// The width and height of a tile, in pixels.
+const TILE: usize = 64;
+
+/// The space between tiles, and around them, in pixels.
+const GAP: usize = 8;
+
+/// The height of the band above the board holding the caption and score, in pixels.
+const HEADER: usize = 32;
+
+/// The representation of a change to a tile made by the last movement, marked when rendered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mark {
+    /// The cell of the tile was formed by a merge.
+    Merged,
+    /// The cell of the tile was spawned.
+    Spawned,
+}
+
+/// The representation of a tile of a rendered board.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tile {
+    /// The text written on the tile, which is empty unless it holds a cell.
+    pub label: String,
+    /// The colour of the tile.
+    pub fill: Colour,
+    /// The colour of the text written on the tile.
+    pub ink: Colour,
+    /// The change made to the tile by the last movement, if any.
+    pub mark: Option<Mark>,
+}
+
+/// The representation of a position of a game, as rendered.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    /// The line describing the position, e.g. `move 3: left`.
+    pub caption: String,
+    /// The score of the position.
+    pub score: usize,
+    /// The tiles of the board, row by row, or `None` where a coordinate lies off the board.
+    pub tiles: Vec<Vec<Option<Tile>>>,
+    /// The board as written in text.
+    pub text: String,
+}
+
+impl Frame {
+    /// Renders the position of a board.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the board to render
+    /// * `caption` - the line describing the position
+    /// * `events` - the events that followed the movement leading to the position
+    pub fn new(board: &Board, caption: &str, events: &[Event]) -> Self {
+        let rules = board.rules();
+        let cells = board.cells();
+        let smallest = Value::from(rules.variant.rule().spawns()[0]);
+        let mark = |pos| {
+            events.iter().rev().find_map(|event| match event {
+                Event::Merge { into, .. } if *into == pos => Some(Mark::Merged),
+                Event::Spawn { at, .. } if *at == pos => Some(Mark::Spawned),
+                _ => None,
+            })
+        };
+
+        let (rows, cols) = rules.grid_size();
+        let tiles = (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(|col| {
+                        let pos = (row, col);
+                        if !rules.contains(pos) {
+                            return None;
+                        }
+                        let (fill, ink) = match &cells[row][col] {
+                            _ if rules.is_wall(pos) => (DARK, LIGHT),
+                            None => (EMPTY, DARK),
+                            Some(cell) => match cell.special() {
+                                Some(special) => special_colours(special),
+                                None => value_colours(cell.value(), &smallest),
+                            },
+                        };
+                        Some(Tile {
+                            label: cells[row][col]
+                                .as_ref()
+                                .map_or(String::new(), |cell| cell.to_string()),
+                            fill,
+                            ink,
+                            mark: mark(pos),
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            caption: caption.to_string(),
+            score: board.score(),
+            tiles,
+            text: board.to_string(),
+        }
+    }
+}
+
+/// Returns the width and height of the image of a frame, in pixels.
+///
+/// # Arguments
+///
+/// * `frame` - the frame
+fn size(frame: &Frame) -> (usize, usize) {
+    let rows = frame.tiles.len();
+    let cols = frame.tiles.first().map_or(0, Vec::len);
+    (
+        cols * (TILE + GAP) + GAP,
+        HEADER + rows * (TILE + GAP) + GAP,
+    )
+}
+
+/// Returns the coordinates of the top left corner of a tile in the image of a frame, in pixels.
+///
+/// # Arguments
+///
+/// * `pos` - the coordinate of the tile on the board
+fn origin(pos: (usize, usize)) -> (usize, usize) {
+    (
+        GAP + pos.1 * (TILE + GAP),
+        HEADER + GAP + pos.0 * (TILE + GAP),
+    )
+}
+
+/// Returns the colours of a tile holding a value, and of its text.
+///
+/// # Arguments
+///
+/// * `value` - the value of the tile
+/// * `smallest` - the smallest value spawned by the rules
+fn value_colours(value: &Value, smallest: &Value) -> (Colour, Colour) {
+    let rank = match value > smallest {
+        true => (value / smallest).bits().saturating_sub(1) as usize,
+        false => 0,
+    };
+    let fill = TILES[rank.min(TILES.len() - 1)];
+    let ink = match rank {
+        0 | 1 => DARK,
+        _ => LIGHT,
+    };
+    (fill, ink)
+}
+
+/// Returns the colours of a special tile, and of its text.
+///
+/// # Arguments
+///
+/// * `special` - the kind of the tile
+fn special_colours(special: Special) -> (Colour, Colour) {
+    let fill = match special {
+        Special::Joker => Colour(0x9b, 0x59, 0xb6),
+        Special::Bomb => Colour(0x2c, 0x3e, 0x50),
+        Special::Doubler => Colour(0x1a, 0xbc, 0x9c),
+    };
+    (fill, LIGHT)
+}
+
+/// Renders every position of a replay, from its starting board to the board after its last step.
+/// Fails if the replay does not match its seed, as its positions could not be trusted.
+///
+/// # Arguments
+///
+/// * `replay` - the replay to render
+pub fn frames(replay: &Replay) -> Result<Vec<Frame>, String> {
+    if let Some(divergence) = crate::replay::verify(replay)?.divergence {
+        return Err(format!(
+            "the replay does not match its seed: {}",
+            divergence
+        ));
+    }
+
+    let mut board = Board::with_rules(replay.rules.clone(), replay.seed);
+    let mut frames = vec![Frame::new(&board, "start", &[])];
+    for (idx, step) in replay.steps.iter().enumerate() {
+        // the replay was verified, so every step can be played
+        let events = board.movement(step.mov).unwrap();
+        let caption = format!("move {}: {}", idx + 1, step.mov);
+        frames.push(Frame::new(&board, &caption, &events));
+    }
+    Ok(frames)
+}
+
+/// Returns every frame written as text, one after another, for spectators following a game in a
+/// terminal.
+///
+/// # Arguments
+///
+/// * `frames` - the frames to write
+pub fn text(frames: &[Frame]) -> String {
+    frames
+        .iter()
+        .map(|frame| {
+            format!(
+                "{}\nscore: {}\n\n{}\n",
+                frame.caption, frame.score, frame.text
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// The representation of the form a replay is exported in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    /// Every position written as text.
+    Text,
+    /// A directory of SVG images, one for each position.
+    Frames,
+    /// An animated SVG image.
+    Svg,
+    /// An animated GIF image.
+    Gif,
+}
+
+/// Implementation of the `FromStr` trait for `Format`.
+impl std::str::FromStr for Format {
+    type Err = ();
+
+    /// Parses a format from its lowercase name, `text`, `frames`, `svg` or `gif`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "frames" => Ok(Format::Frames),
+            "svg" => Ok(Format::Svg),
+            "gif" => Ok(Format::Gif),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Writes an export to the specified file, creating its directory if needed.
+///
+/// # Arguments
+///
+/// * `path` - the path of the file
+/// * `contents` - the contents of the file
+fn write(path: &Path, contents: &[u8]) -> Result<(), String> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    std::fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Runs the `export` mode: renders every position of the replay given as a positional argument,
+/// after verifying it against its seed. Given `--format text` (the default), the positions are
+/// written to stdout as text. Given `--format svg` or `--format gif`, they are written as an
+/// animated image to the file given by `--out`, and given `--format frames`, as one SVG image each
+/// in the directory given by `--out`. Each frame of an animation is shown for `--delay`
+/// milliseconds (500 by default).
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn export(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let usage = "usage: export [--format text|frames|svg|gif] [--out PATH] [--delay MS] FILE";
+    let [file] = args.positional() else {
+        return Err(usage.to_string());
+    };
+    let format = args.get::<Format>("format")?.unwrap_or(Format::Text);
+    let delay = Duration::from_millis(args.get::<u64>("delay")?.unwrap_or(500));
+    let out = args.get::<String>("out")?;
+
+    let frames = frames(&Replay::load(Path::new(file))?)?;
+    let out = match (format, out) {
+        (Format::Text, None) => {
+            print!("{}", text(&frames));
+            return Ok(());
+        }
+        (_, Some(out)) => out,
+        (_, None) => return Err(usage.to_string()),
+    };
+    let out = Path::new(&out);
+
+    match format {
+        Format::Text => write(out, text(&frames).as_bytes())?,
+        Format::Svg => write(out, animated(&frames, delay).as_bytes())?,
+        Format::Gif => write(out, &gif(&frames, delay)?)?,
+        Format::Frames => {
+            for (idx, frame) in frames.iter().enumerate() {
+                let path = out.join(format!("frame-{:04}.svg", idx));
+                write(&path, svg_snapshot(frame).as_bytes())?;
+            }
+        }
+    }
+    println!("exported {} frames to {}", frames.len(), out.display());
+    Ok(())
+}
+
+/// Runs the `snapshot` mode: renders a single position of the replay given as a positional
+/// argument, after verifying it against its seed. The position is the one after the step given by
+/// `--step` (counting from one, with `0` for the starting board), or the last one. Given
+/// `--format svg` (the default), it is written as an SVG image to the file given by `--out`, or to
+/// stdout, and given `--format png`, as a PNG image to the file given by `--out`.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn snapshot(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let usage = "usage: snapshot [--format svg|png] [--step N] [--out PATH] FILE";
+    let [file] = args.positional() else {
+        return Err(usage.to_string());
+    };
+    let format = args.get::<String>("format")?;
+    let out = args.get::<String>("out")?;
+
+    let frames = frames(&Replay::load(Path::new(file))?)?;
+    let step = args.get::<usize>("step")?.unwrap_or(frames.len() - 1);
+    let frame = frames
+        .get(step)
+        .ok_or(format!("the replay has only {} steps", frames.len() - 1))?;
+
+    match (format.as_deref(), out) {
+        (None | Some("svg"), None) => print!("{}", svg_snapshot(frame)),
+        (None | Some("svg"), Some(out)) => write(Path::new(&out), svg_snapshot(frame).as_bytes())?,
+        (Some("png"), Some(out)) => write(Path::new(&out), &png(frame)?)?,
+        _ => return Err(usage.to_string()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
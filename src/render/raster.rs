@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::{origin, size, Colour, Frame, Mark, BOARD, DARK, GAP, HEADER, MARK, PAGE, TILE};
+
+/// The width of a glyph of the font, in font pixels.
+const GLYPH_WIDTH: usize = 3;
+
+/// The height of a glyph of the font, in font pixels.
+const GLYPH_HEIGHT: usize = 5;
+
+/// Returns the glyph of a character in the font, as rows of `#` (drawn) and `.` (blank) separated
+/// by commas, or `None` if the font has no glyph for it. Letters are drawn in upper case.
+///
+/// # Arguments
+///
+/// * `c` - the character
+fn glyph(c: char) -> Option<&'static str> {
+    Some(match c.to_ascii_uppercase() {
+        '0' => "###,#.#,#.#,#.#,###",
+        '1' => ".#.,##.,.#.,.#.,###",
+        '2' => "###,..#,###,#..,###",
+        '3' => "###,..#,.##,..#,###",
+        '4' => "#.#,#.#,###,..#,..#",
+        '5' => "###,#..,###,..#,###",
+        '6' => "###,#..,###,#.#,###",
+        '7' => "###,..#,..#,.#.,.#.",
+        '8' => "###,#.#,###,#.#,###",
+        '9' => "###,#.#,###,..#,###",
+        'A' => ".#.,#.#,###,#.#,#.#",
+        'B' => "##.,#.#,##.,#.#,##.",
+        'C' => ".##,#..,#..,#..,.##",
+        'D' => "##.,#.#,#.#,#.#,##.",
+        'E' => "###,#..,##.,#..,###",
+        'F' => "###,#..,##.,#..,#..",
+        'G' => ".##,#..,#.#,#.#,.##",
+        'H' => "#.#,#.#,###,#.#,#.#",
+        'I' => "###,.#.,.#.,.#.,###",
+        'J' => "..#,..#,..#,#.#,.#.",
+        'K' => "#.#,#.#,##.,#.#,#.#",
+        'L' => "#..,#..,#..,#..,###",
+        'M' => "#.#,###,###,#.#,#.#",
+        'N' => "##.,#.#,#.#,#.#,#.#",
+        'O' => ".#.,#.#,#.#,#.#,.#.",
+        'P' => "##.,#.#,##.,#..,#..",
+        'Q' => ".#.,#.#,#.#,##.,.##",
+        'R' => "##.,#.#,##.,#.#,#.#",
+        'S' => ".##,#..,.#.,..#,##.",
+        'T' => "###,.#.,.#.,.#.,.#.",
+        'U' => "#.#,#.#,#.#,#.#,###",
+        'V' => "#.#,#.#,#.#,#.#,.#.",
+        'W' => "#.#,#.#,###,###,#.#",
+        'X' => "#.#,#.#,.#.,#.#,#.#",
+        'Y' => "#.#,#.#,.#.,.#.,.#.",
+        'Z' => "###,..#,.#.,#..,###",
+        ':' => "...,.#.,...,.#.,...",
+        '#' => "#.#,###,#.#,###,#.#",
+        _ => return None,
+    })
+}
+
+/// The representation of an image being drawn, as rows of pixels.
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<Colour>,
+}
+
+impl Canvas {
+    /// Creates a canvas of the specified size, filled with a colour.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - the width of the canvas, in pixels
+    /// * `height` - the height of the canvas, in pixels
+    /// * `colour` - the colour to fill the canvas with
+    fn new(width: usize, height: usize, colour: Colour) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![colour; width * height],
+        }
+    }
+
+    /// Fills a rectangle with a colour, leaving out any part lying off the canvas.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the left edge of the rectangle
+    /// * `y` - the top edge of the rectangle
+    /// * `width` - the width of the rectangle
+    /// * `height` - the height of the rectangle
+    /// * `colour` - the colour to fill the rectangle with
+    fn fill(&mut self, x: usize, y: usize, width: usize, height: usize, colour: Colour) {
+        for row in y..(y + height).min(self.height) {
+            for col in x..(x + width).min(self.width) {
+                self.pixels[row * self.width + col] = colour;
+            }
+        }
+    }
+
+    /// Draws the outline of a rectangle, inside its edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the left edge of the rectangle
+    /// * `y` - the top edge of the rectangle
+    /// * `size` - the width and height of the rectangle
+    /// * `thickness` - the thickness of the outline
+    /// * `colour` - the colour of the outline
+    fn outline(&mut self, x: usize, y: usize, size: usize, thickness: usize, colour: Colour) {
+        self.fill(x, y, size, thickness, colour);
+        self.fill(x, y + size - thickness, size, thickness, colour);
+        self.fill(x, y, thickness, size, colour);
+        self.fill(x + size - thickness, y, thickness, size, colour);
+    }
+
+    /// Writes text in the font, each font pixel drawn as a square of the specified scale, and each
+    /// character followed by a blank column. Characters missing from the font are left blank.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the left edge of the text
+    /// * `y` - the top edge of the text
+    /// * `text` - the text to write
+    /// * `scale` - the size of a font pixel, in pixels
+    /// * `colour` - the colour of the text
+    fn write(&mut self, x: usize, y: usize, text: &str, scale: usize, colour: Colour) {
+        for (idx, c) in text.chars().enumerate() {
+            let Some(glyph) = glyph(c) else {
+                continue;
+            };
+            let left = x + idx * (GLYPH_WIDTH + 1) * scale;
+            for (row, line) in glyph.split(',').enumerate() {
+                for (col, _) in line.chars().enumerate().filter(|(_, c)| *c == '#') {
+                    self.fill(left + col * scale, y + row * scale, scale, scale, colour);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the width of text written in the font at the specified scale, in pixels.
+///
+/// # Arguments
+///
+/// * `text` - the text
+/// * `scale` - the size of a font pixel, in pixels
+fn text_width(text: &str, scale: usize) -> usize {
+    (text.chars().count() * (GLYPH_WIDTH + 1)).saturating_sub(1) * scale
+}
+
+/// Draws a frame onto a canvas of its size.
+///
+/// # Arguments
+///
+/// * `frame` - the frame to draw
+fn paint(frame: &Frame) -> Canvas {
+    let (width, height) = size(frame);
+    let mut canvas = Canvas::new(width, height, PAGE);
+    let top = (HEADER - GLYPH_HEIGHT * 2) / 2;
+    canvas.write(GAP, top, &frame.caption, 2, DARK);
+    let score = format!("score: {}", frame.score);
+    let left = width.saturating_sub(GAP + text_width(&score, 2));
+    canvas.write(left, top, &score, 2, DARK);
+    canvas.fill(0, HEADER, width, height - HEADER, BOARD);
+
+    for (row, tiles) in frame.tiles.iter().enumerate() {
+        for (col, tile) in tiles.iter().enumerate() {
+            let Some(tile) = tile else {
+                continue;
+            };
+            let (x, y) = origin((row, col));
+            canvas.fill(x, y, TILE, TILE, tile.fill);
+            match tile.mark {
+                Some(Mark::Merged) => canvas.outline(x, y, TILE, 4, MARK),
+                Some(Mark::Spawned) => canvas.outline(x, y, TILE, 2, MARK),
+                None => {}
+            }
+
+            // the largest scale at which the label fits within the tile, leaving a margin
+            let scale = (1..=6)
+                .rev()
+                .find(|scale| text_width(&tile.label, *scale) <= TILE - 8)
+                .unwrap_or(1);
+            canvas.write(
+                (x + TILE / 2).saturating_sub(text_width(&tile.label, scale) / 2),
+                y + (TILE - GLYPH_HEIGHT * scale) / 2,
+                &tile.label,
+                scale,
+                tile.ink,
+            );
+        }
+    }
+    canvas
+}
+
+/// Returns the colours used by canvases, and each canvas as the indices of its pixels' colours.
+///
+/// # Arguments
+///
+/// * `canvases` - the canvases
+fn indexed(canvases: &[Canvas]) -> Result<(Vec<Colour>, Vec<Vec<u8>>), String> {
+    let mut palette = Vec::new();
+    let mut indices = HashMap::new();
+    let mut images = Vec::new();
+    for canvas in canvases {
+        let mut image = Vec::with_capacity(canvas.pixels.len());
+        for colour in &canvas.pixels {
+            let idx = match indices.get(colour) {
+                Some(idx) => *idx,
+                None => {
+                    let idx = u8::try_from(palette.len())
+                        .map_err(|_| "too many colours to index".to_string())?;
+                    palette.push(*colour);
+                    indices.insert(*colour, idx);
+                    idx
+                }
+            };
+            image.push(idx);
+        }
+        images.push(image);
+    }
+    Ok((palette, images))
+}
+
+/// Returns the colours of a palette as consecutive red, green and blue bytes.
+///
+/// # Arguments
+///
+/// * `palette` - the palette
+fn flatten(palette: &[Colour]) -> Vec<u8> {
+    palette
+        .iter()
+        .flat_map(|colour| [colour.0, colour.1, colour.2])
+        .collect()
+}
+
+/// Returns a frame drawn as a PNG image.
+///
+/// # Arguments
+///
+/// * `frame` - the frame to draw
+pub fn png(frame: &Frame) -> Result<Vec<u8>, String> {
+    let canvas = paint(frame);
+    let (palette, images) = indexed(std::slice::from_ref(&canvas))?;
+
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, canvas.width as u32, canvas.height as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(flatten(&palette));
+    let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+    writer
+        .write_image_data(&images[0])
+        .map_err(|e| e.to_string())?;
+    writer.finish().map_err(|e| e.to_string())?;
+    Ok(bytes)
+}
+
+/// Returns the frames drawn as an animated GIF image, showing each in turn for the specified delay
+/// and then starting over. Every frame must be of the same size. Fails if there are no frames.
+///
+/// # Arguments
+///
+/// * `frames` - the frames to draw
+/// * `delay` - the time each frame is shown for
+pub fn gif(frames: &[Frame], delay: Duration) -> Result<Vec<u8>, String> {
+    let canvases = frames.iter().map(paint).collect::<Vec<Canvas>>();
+    let first = canvases.first().ok_or("there are no frames to draw")?;
+    let (width, height) = (
+        u16::try_from(first.width).map_err(|_| "the frames are too wide")?,
+        u16::try_from(first.height).map_err(|_| "the frames are too tall")?,
+    );
+    let (palette, images) = indexed(&canvases)?;
+    // a delay is given in hundredths of a second
+    let delay = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+
+    let mut bytes = Vec::new();
+    let mut encoder = gif::Encoder::new(&mut bytes, width, height, &flatten(&palette))
+        .map_err(|e| e.to_string())?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(|e| e.to_string())?;
+    for image in images {
+        let mut frame = gif::Frame::from_indexed_pixels(width, height, image, None);
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(|e| e.to_string())?;
+    }
+    drop(encoder);
+    Ok(bytes)
+}
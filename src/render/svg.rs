@@ -0,0 +1,170 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use super::{origin, size, Frame, Mark, BOARD, DARK, GAP, HEADER, MARK, PAGE, TILE};
+
+/// Returns text escaped for use in SVG.
+///
+/// # Arguments
+///
+/// * `text` - the text to escape
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Returns the elements drawing a frame, without the enclosing `svg` element.
+///
+/// # Arguments
+///
+/// * `frame` - the frame to draw
+fn elements(frame: &Frame) -> String {
+    let (width, height) = size(frame);
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<rect width="{}" height="{}" fill="{}"/>"#,
+        width, height, PAGE
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        r#"<text x="{}" y="{}" font-size="16" fill="{}">{}</text>"#,
+        GAP,
+        HEADER - 10,
+        DARK,
+        escape(&frame.caption)
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        r#"<text x="{}" y="{}" font-size="16" fill="{}" text-anchor="end">score: {}</text>"#,
+        width - GAP,
+        HEADER - 10,
+        DARK,
+        frame.score
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        r#"<rect y="{}" width="{}" height="{}" rx="6" fill="{}"/>"#,
+        HEADER,
+        width,
+        height - HEADER,
+        BOARD
+    )
+    .unwrap();
+
+    for (row, tiles) in frame.tiles.iter().enumerate() {
+        for (col, tile) in tiles.iter().enumerate() {
+            let Some(tile) = tile else {
+                continue;
+            };
+            let (x, y) = origin((row, col));
+            let stroke = match tile.mark {
+                Some(Mark::Merged) => format!(r#" stroke="{}" stroke-width="4""#, MARK),
+                Some(Mark::Spawned) => format!(
+                    r#" stroke="{}" stroke-width="3" stroke-dasharray="6 4""#,
+                    MARK
+                ),
+                None => String::new(),
+            };
+            writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" rx="4" fill="{}"{}/>"#,
+                x, y, TILE, TILE, tile.fill, stroke
+            )
+            .unwrap();
+            if tile.label.is_empty() {
+                continue;
+            }
+            let font_size = (TILE * 8 / 5 / tile.label.chars().count().max(2)).min(32);
+            writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-size="{}" fill="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                x + TILE / 2,
+                y + TILE / 2,
+                font_size,
+                tile.ink,
+                escape(&tile.label)
+            )
+            .unwrap();
+        }
+    }
+    svg
+}
+
+/// Returns the opening tag of an SVG image of the size of a frame.
+///
+/// # Arguments
+///
+/// * `frame` - the frame the image is sized by
+fn open(frame: &Frame) -> String {
+    let (width, height) = size(frame);
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}" font-family="Helvetica, Arial, sans-serif" font-weight="bold">"#,
+        width, height
+    )
+}
+
+/// Returns a frame drawn as an SVG image.
+///
+/// # Arguments
+///
+/// * `frame` - the frame to draw
+pub fn snapshot(frame: &Frame) -> String {
+    format!("{}\n{}</svg>\n", open(frame), elements(frame))
+}
+
+/// Returns the frames drawn as an animated SVG image, showing each in turn for the specified
+/// delay and then starting over. The image is sized by the first frame, and is empty if there
+/// are no frames.
+///
+/// # Arguments
+///
+/// * `frames` - the frames to draw
+/// * `delay` - the time each frame is shown for
+pub fn animated(frames: &[Frame], delay: Duration) -> String {
+    let Some(first) = frames.first() else {
+        return "<svg xmlns=\"http://www.w3.org/2000/svg\"/>\n".to_string();
+    };
+    let count = frames.len();
+    let duration = delay.as_secs_f64() * count as f64;
+
+    let mut svg = format!("{}\n", open(first));
+    for (idx, frame) in frames.iter().enumerate() {
+        // each frame is visible from its own share of the animation to the next, which the
+        // discrete animation of its visibility describes as the times at which it changes
+        let mut values = Vec::new();
+        let mut times = Vec::new();
+        if idx > 0 {
+            values.push("hidden");
+            times.push(0.0);
+        }
+        values.push("visible");
+        times.push(idx as f64 / count as f64);
+        if idx + 1 < count {
+            values.push("hidden");
+            times.push((idx + 1) as f64 / count as f64);
+        }
+        let times = times
+            .iter()
+            .map(|time| format!("{:.6}", time))
+            .collect::<Vec<String>>();
+
+        writeln!(svg, r#"<g visibility="hidden">"#).unwrap();
+        writeln!(
+            svg,
+            r#"<animate attributeName="visibility" values="{}" keyTimes="{}" calcMode="discrete" dur="{:.3}s" repeatCount="indefinite"/>"#,
+            values.join(";"),
+            times.join(";"),
+            duration
+        )
+        .unwrap();
+        svg.push_str(&elements(frame));
+        writeln!(svg, "</g>").unwrap();
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+/// Affirm that each tile is labelled with its cell and coloured by its value, and that empty
+/// tiles are left blank.
+#[test]
+fn tiles() {
+    let board = "2,4,8,2048/0,0,0,0/0,0,0,4096/0,0,0,0"
+        .parse::<Board>()
+        .unwrap();
+    let frame = Frame::new(&board, "start", &[]);
+    let tile = |row: usize, col: usize| frame.tiles[row][col].clone().unwrap();
+
+    assert_eq!(4, frame.tiles.len());
+    assert_eq!("2", tile(0, 0).label);
+    assert_eq!(Colour(0xee, 0xe4, 0xda), tile(0, 0).fill);
+    assert_eq!(DARK, tile(0, 1).ink);
+    assert_eq!(LIGHT, tile(0, 2).ink);
+    assert_eq!(Colour(0xed, 0xc2, 0x2e), tile(0, 3).fill);
+    assert_eq!(Colour(0x3c, 0x3a, 0x32), tile(2, 3).fill);
+    assert_eq!("", tile(1, 0).label);
+    assert_eq!(EMPTY, tile(1, 0).fill);
+    assert_eq!(None, tile(0, 0).mark);
+    assert_eq!(board.to_string(), frame.text);
+}
+
+/// Affirm that the tiles formed by a merge or spawned by the movement are marked.
+#[test]
+fn marks() {
+    let mut board = Board::with_seed(3);
+    board.place((0, 0), Cell::new(2)).unwrap();
+    board.place((0, 1), Cell::new(2)).unwrap();
+    let events = board.movement(Move::Shift(Direction::Left)).unwrap();
+    let frame = Frame::new(&board, "move 1: left", &events);
+
+    let spawned = events
+        .iter()
+        .find_map(|event| match event {
+            Event::Spawn { at, .. } => Some(*at),
+            _ => None,
+        })
+        .unwrap();
+    let tile = |pos: (usize, usize)| frame.tiles[pos.0][pos.1].clone().unwrap();
+    assert_eq!(Some(Mark::Merged), tile((0, 0)).mark);
+    assert_eq!(Some(Mark::Spawned), tile(spawned).mark);
+    assert_eq!(board.score(), frame.score);
+}
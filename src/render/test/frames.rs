@@ -0,0 +1,37 @@
+use super::*;
+
+/// Affirm that every position of a replay is rendered, from its start, with each movement in its
+/// caption.
+#[test]
+fn rendered() {
+    let replay = replay(7, &moves(12));
+    let frames = frames(&replay).unwrap();
+
+    assert_eq!(replay.steps.len() + 1, frames.len());
+    assert_eq!("start", frames[0].caption);
+    assert_eq!(0, frames[0].score);
+    assert_eq!(
+        format!("move 1: {}", replay.steps[0].mov),
+        frames[1].caption
+    );
+    assert_eq!(replay.score, frames.last().unwrap().score);
+}
+
+/// Affirm that a replay which does not match its seed is not rendered.
+#[test]
+fn diverged() {
+    let mut replay = replay(7, &moves(12));
+    replay.seed += 1;
+    assert!(frames(&replay).is_err());
+}
+
+/// Affirm that the positions of a replay are written as text, one after another.
+#[test]
+fn as_text() {
+    let replay = replay(7, &moves(2));
+    let frames = frames(&replay).unwrap();
+    let text = text(&frames);
+    assert!(text.starts_with(&format!("start\nscore: 0\n\n{}\n", frames[0].text)));
+    assert!(text.contains(&frames[1].caption));
+    assert!(text.ends_with(&format!("{}\n", frames.last().unwrap().text)));
+}
@@ -0,0 +1,8 @@
+use super::*;
+use crate::replay::test::{moves, replay};
+use crate::{Cell, Direction, Move};
+
+mod frame;
+mod frames;
+mod raster;
+mod svg;
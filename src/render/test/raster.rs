@@ -0,0 +1,50 @@
+use super::*;
+
+/// Affirm that a PNG snapshot decodes to an image of the size of the board, coloured as the SVG
+/// snapshot is.
+#[test]
+fn png_snapshot() {
+    let board = "2,0,0,0/0,0,0,0/0,0,0,0/0,0,0,0".parse::<Board>().unwrap();
+    let bytes = png(&Frame::new(&board, "start", &[])).unwrap();
+
+    let mut decoder = ::png::Decoder::new(bytes.as_slice());
+    decoder.set_transformations(::png::Transformations::EXPAND);
+    let mut reader = decoder.read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!((296, 328), (info.width, info.height));
+
+    let pixel = |x: usize, y: usize| {
+        let idx = (y * info.width as usize + x) * 3;
+        Colour(pixels[idx], pixels[idx + 1], pixels[idx + 2])
+    };
+    assert_eq!(PAGE, pixel(0, 0));
+    assert_eq!(BOARD, pixel(2, 34));
+    assert_eq!(Colour(0xee, 0xe4, 0xda), pixel(9, 41));
+    assert_eq!(EMPTY, pixel(81, 41));
+}
+
+/// Affirm that an animated GIF holds every frame, each shown for the delay, looping forever.
+#[test]
+fn gif_animation() {
+    let frames = frames(&replay(7, &moves(3))).unwrap();
+    let bytes = gif(&frames, Duration::from_millis(250)).unwrap();
+
+    let mut decoder = ::gif::DecodeOptions::new()
+        .read_info(bytes.as_slice())
+        .unwrap();
+    assert_eq!((296, 328), (decoder.width(), decoder.height()));
+    let mut count = 0;
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!(25, frame.delay);
+        count += 1;
+    }
+    assert_eq!(frames.len(), count);
+    assert_eq!(::gif::Repeat::Infinite, decoder.repeat());
+}
+
+/// Affirm that no GIF is drawn without frames.
+#[test]
+fn gif_empty() {
+    assert!(gif(&[], Duration::from_millis(250)).is_err());
+}
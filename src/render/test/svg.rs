@@ -0,0 +1,38 @@
+use super::*;
+
+/// Affirm that a snapshot is a single SVG image sized by the board, holding every label in the
+/// colours of its tile.
+#[test]
+fn snapshot() {
+    let board = "2,0,0,0/0,0,0,0/0,0,0,0/0,0,0,128"
+        .parse::<Board>()
+        .unwrap();
+    let svg = svg_snapshot(&Frame::new(&board, "start", &[]));
+
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains(r#"width="296" height="328""#));
+    let label = |ink: Colour, label: &str| {
+        format!(
+            r#"fill="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+            ink, label
+        )
+    };
+    assert!(svg.contains(&label(LIGHT, "128")));
+    assert!(svg.contains(&label(DARK, "2")));
+    assert!(svg.contains(r##"fill="#edcf72""##));
+    assert!(svg.contains("score: 0"));
+}
+
+/// Affirm that an animation holds every frame, each visible for its own share of the loop.
+#[test]
+fn animation() {
+    let frames = frames(&replay(7, &moves(3))).unwrap();
+    let svg = animated(&frames, Duration::from_millis(500));
+
+    assert_eq!(frames.len(), svg.matches("<animate ").count());
+    assert!(svg.contains(r#"values="visible;hidden" keyTimes="0.000000;0.250000""#));
+    assert!(svg.contains(r#"values="hidden;visible;hidden" keyTimes="0.000000;0.250000;0.500000""#));
+    assert!(svg.contains(r#"values="hidden;visible" keyTimes="0.000000;0.750000""#));
+    assert!(svg.contains(r#"dur="2.000s""#));
+}
@@ -0,0 +1,310 @@
+//! Replays of whole games, recording every movement and the cell spawned after it, so that a
+//! shared score can be checked by playing the game again from its seed.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::board::{Board, BoardCoord, Event, Rules};
+use crate::Move;
+
+/// The representation of a cell spawned after a movement, as recorded in a replay.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Spawn {
+    /// The coordinate at which the cell was spawned.
+    pub at: BoardCoord,
+    /// The cell spawned, written in board notation.
+    pub cell: String,
+}
+
+/// Implementation of the `Display` trait for `Spawn`.
+impl std::fmt::Display for Spawn {
+    /// Writes the spawn as e.g. `2 at (1, 3)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at ({}, {})", self.cell, self.at.0, self.at.1)
+    }
+}
+
+/// The representation of a movement of a replay and the cell spawned after it, if any.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Step {
+    /// The movement played.
+    #[serde(rename = "move")]
+    pub mov: Move,
+    /// The cell spawned after the movement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spawn: Option<Spawn>,
+}
+
+/// The representation of a whole game as recorded, with the outcome claimed for it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    /// The name of the player.
+    #[serde(default)]
+    pub name: String,
+    /// The seed of the random number generator used for spawning cells.
+    pub seed: u64,
+    /// The rules the game was played by.
+    #[serde(default)]
+    pub rules: Rules,
+    /// The notation of the board the game started from.
+    pub start: String,
+    /// Every movement of the game, in order.
+    pub steps: Vec<Step>,
+    /// The final score claimed.
+    pub score: usize,
+    /// The value of the largest cell on the final board claimed.
+    pub max_value: usize,
+}
+
+impl Replay {
+    /// Begins recording the game played on a board, which must not have been moved yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the player
+    /// * `board` - the board of the game
+    /// * `seed` - the seed the board was created with
+    pub fn new(name: &str, board: &Board, seed: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            seed,
+            rules: board.rules().clone(),
+            start: board.notation(),
+            steps: Vec::new(),
+            score: board.score(),
+            max_value: board.max_value(),
+        }
+    }
+
+    /// Loads a replay from a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - the path of the file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Records a movement played on the board, and the outcome of the game as it stands.
+    ///
+    /// # Arguments
+    ///
+    /// * `mov` - the movement played
+    /// * `events` - the events that followed the movement
+    /// * `board` - the board after the movement
+    pub fn record(&mut self, mov: Move, events: &[Event], board: &Board) {
+        self.steps.push(Step {
+            mov,
+            spawn: spawned(events),
+        });
+        self.score = board.score();
+        self.max_value = board.max_value();
+    }
+}
+
+/// Returns the cell spawned among the events that followed a movement, if any.
+///
+/// # Arguments
+///
+/// * `events` - the events that followed the movement
+fn spawned(events: &[Event]) -> Option<Spawn> {
+    events.iter().find_map(|event| match event {
+        Event::Spawn { at, cell } => Some(Spawn {
+            at: *at,
+            cell: cell.to_string(),
+        }),
+        _ => None,
+    })
+}
+
+/// The representation of the first point at which a replay differs from the game played again.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Divergence {
+    /// The game started from another board than the one recorded.
+    Start { expected: String, claimed: String },
+    /// The movement of the (one-indexed) step could not be played.
+    IllegalMove { step: usize, mov: Move },
+    /// Another cell (or none) was spawned after the movement of the (one-indexed) step.
+    Spawn {
+        step: usize,
+        expected: Option<Spawn>,
+        claimed: Option<Spawn>,
+    },
+    /// The game ended with another score than the one claimed.
+    Score { expected: usize, claimed: usize },
+    /// The game ended with another largest cell than the one claimed.
+    MaxValue { expected: usize, claimed: usize },
+}
+
+/// Implementation of the `Display` trait for `Divergence`.
+impl std::fmt::Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let spawn = |spawn: &Option<Spawn>| match spawn {
+            Some(spawn) => spawn.to_string(),
+            None => "nothing".to_string(),
+        };
+        match self {
+            Divergence::Start { expected, claimed } => write!(
+                f,
+                "the game starts from {}, but the replay starts from {}",
+                expected, claimed
+            ),
+            Divergence::IllegalMove { step, mov } => {
+                write!(f, "move {}: {} cannot be played", step, mov)
+            }
+            Divergence::Spawn {
+                step,
+                expected,
+                claimed,
+            } => write!(
+                f,
+                "move {}: the seed spawns {}, but the replay spawns {}",
+                step,
+                spawn(expected),
+                spawn(claimed)
+            ),
+            Divergence::Score { expected, claimed } => write!(
+                f,
+                "the game scores {}, but the replay claims {}",
+                expected, claimed
+            ),
+            Divergence::MaxValue { expected, claimed } => write!(
+                f,
+                "the game reaches a largest cell of {}, but the replay claims {}",
+                expected, claimed
+            ),
+        }
+    }
+}
+
+/// The representation of the result of verifying a replay.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    /// The number of steps played again before verification stopped.
+    pub steps: usize,
+    /// The number of those steps which were undos.
+    pub undos: usize,
+    /// The notation of the board when verification stopped.
+    pub board: String,
+    /// The score of the board when verification stopped.
+    pub score: usize,
+    /// The first divergence found, if any.
+    pub divergence: Option<Divergence>,
+}
+
+/// Implementation of the `Display` trait for `Report`.
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.divergence {
+            None => writeln!(f, "verified {} moves", self.steps)?,
+            Some(divergence) => writeln!(f, "diverged: {}", divergence)?,
+        }
+        writeln!(f, "undos: {}", self.undos)?;
+        writeln!(f, "score: {}", self.score)?;
+        write!(f, "board: {}", self.board)
+    }
+}
+
+/// Verifies a replay by playing it again from its seed: the starting board and every spawn must
+/// match those of the seed, every movement must be legal, and the final score and largest cell
+/// must match those claimed. Verification stops at the first divergence. Fails if the rules of the
+/// replay are invalid.
+///
+/// # Arguments
+///
+/// * `replay` - the replay to verify
+pub fn verify(replay: &Replay) -> Result<Report, String> {
+    replay
+        .rules
+        .validate()
+        .map_err(|_| format!("invalid rules: {}", replay.rules))?;
+    let mut board = Board::with_rules(replay.rules.clone(), replay.seed);
+    let mut report = Report {
+        steps: 0,
+        undos: 0,
+        board: board.notation(),
+        score: 0,
+        divergence: None,
+    };
+
+    let mut divergence = match board.notation() == replay.start {
+        true => None,
+        false => Some(Divergence::Start {
+            expected: board.notation(),
+            claimed: replay.start.clone(),
+        }),
+    };
+    for (idx, step) in replay.steps.iter().enumerate() {
+        if divergence.is_some() {
+            break;
+        }
+        let events = match board.movement(step.mov) {
+            Ok(events) => events,
+            Err(()) => {
+                divergence = Some(Divergence::IllegalMove {
+                    step: idx + 1,
+                    mov: step.mov,
+                });
+                break;
+            }
+        };
+        report.steps += 1;
+        if step.mov == Move::Undo {
+            report.undos += 1;
+        }
+
+        let expected = spawned(&events);
+        if expected != step.spawn {
+            divergence = Some(Divergence::Spawn {
+                step: idx + 1,
+                expected,
+                claimed: step.spawn.clone(),
+            });
+        }
+    }
+
+    report.board = board.notation();
+    report.score = board.score();
+    report.divergence = divergence.or_else(|| {
+        if board.score() != replay.score {
+            Some(Divergence::Score {
+                expected: board.score(),
+                claimed: replay.score,
+            })
+        } else if board.max_value() != replay.max_value {
+            Some(Divergence::MaxValue {
+                expected: board.max_value(),
+                claimed: replay.max_value,
+            })
+        } else {
+            None
+        }
+    });
+    Ok(report)
+}
+
+/// Runs the `verify` mode: verifies the replay given as a positional argument, printing a report
+/// of the moves played again, the undos among them and the first divergence found, if any. The
+/// mode fails if the replay diverges.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let file = args.positional().first().ok_or("usage: verify FILE")?;
+    let replay = Replay::load(Path::new(file))?;
+
+    let report = verify(&replay)?;
+    println!("{}", report);
+    match report.divergence {
+        None => Ok(()),
+        Some(_) => Err(format!("{} does not match its seed", file)),
+    }
+}
+
+#[cfg(test)]
+pub mod test;
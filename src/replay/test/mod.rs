@@ -0,0 +1,29 @@
+use super::*;
+use crate::Direction;
+
+mod record;
+mod verify;
+
+/// Returns the replay of a game of the specified seed, in which each of the movements is played
+/// in turn, skipping those which cannot be played. The tests of the modes working from replays
+/// record their games with it too.
+pub fn replay(seed: u64, moves: &[Move]) -> Replay {
+    let mut board = Board::with_seed(seed);
+    let mut replay = Replay::new("test", &board, seed);
+    for mov in moves {
+        if let Ok(events) = board.movement(*mov) {
+            replay.record(*mov, &events, &board);
+        }
+    }
+    replay
+}
+
+/// Returns a sequence of movements cycling through every direction, undoing every fifth.
+pub fn moves(count: usize) -> Vec<Move> {
+    (0..count)
+        .map(|idx| match idx % 5 {
+            4 => Move::Undo,
+            n => Move::Shift(Direction::ALL[n]),
+        })
+        .collect()
+}
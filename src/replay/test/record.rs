@@ -0,0 +1,18 @@
+use super::*;
+
+/// Affirm that every movement is recorded with the cell spawned after it, and that a replay is
+/// written and read back as JSON unchanged.
+#[test]
+fn round_trip() {
+    let replay = replay(3, &moves(20));
+    assert!(!replay.steps.is_empty());
+    assert!(replay
+        .steps
+        .iter()
+        .all(|step| step.spawn.is_some() == (step.mov != Move::Undo)));
+
+    let json = serde_json::to_string(&replay).unwrap();
+    assert!(json.contains(r#"{"move":"undo"}"#));
+    assert!(json.contains(r#""move":{"shift":"#));
+    assert_eq!(replay, serde_json::from_str::<Replay>(&json).unwrap());
+}
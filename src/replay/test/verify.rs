@@ -0,0 +1,95 @@
+use super::*;
+
+/// Affirm that a faithful replay is verified in full, counting its undos.
+#[test]
+fn faithful() {
+    let replay = replay(3, &moves(40));
+    let report = verify(&replay).unwrap();
+    assert_eq!(None, report.divergence);
+    assert_eq!(replay.steps.len(), report.steps);
+    assert_eq!(
+        replay
+            .steps
+            .iter()
+            .filter(|step| step.mov == Move::Undo)
+            .count(),
+        report.undos
+    );
+    assert_ne!(0, report.undos);
+    assert_eq!(replay.score, report.score);
+}
+
+/// Affirm that a spawn other than the seed's is reported at the move it followed, and that
+/// verification stops there.
+#[test]
+fn spawn() {
+    let mut replay = replay(3, &moves(40));
+    let expected = replay.steps[5].spawn.clone();
+    replay.steps[5].spawn = Some(Spawn {
+        at: (9, 9),
+        cell: "4".to_string(),
+    });
+
+    let report = verify(&replay).unwrap();
+    assert_eq!(6, report.steps);
+    assert_eq!(
+        Some(Divergence::Spawn {
+            step: 6,
+            expected,
+            claimed: replay.steps[5].spawn.clone(),
+        }),
+        report.divergence
+    );
+}
+
+/// Affirm that a movement which cannot be played is reported.
+#[test]
+fn illegal() {
+    let mut replay = replay(3, &[]);
+    replay.steps.push(Step {
+        mov: Move::Undo,
+        spawn: None,
+    });
+    let report = verify(&replay).unwrap();
+    assert_eq!(0, report.steps);
+    assert_eq!(
+        Some(Divergence::IllegalMove {
+            step: 1,
+            mov: Move::Undo
+        }),
+        report.divergence
+    );
+}
+
+/// Affirm that a starting board, score or largest cell other than the game's is reported.
+#[test]
+fn claims() {
+    let honest = replay(3, &moves(40));
+
+    let mut started = honest.clone();
+    started.seed += 1;
+    assert!(matches!(
+        verify(&started).unwrap().divergence,
+        Some(Divergence::Start { .. })
+    ));
+
+    let mut scored = honest.clone();
+    scored.score += 100;
+    assert_eq!(
+        Some(Divergence::Score {
+            expected: honest.score,
+            claimed: honest.score + 100
+        }),
+        verify(&scored).unwrap().divergence
+    );
+
+    let mut grown = honest.clone();
+    grown.max_value *= 2;
+    assert_eq!(
+        Some(Divergence::MaxValue {
+            expected: honest.max_value,
+            claimed: honest.max_value * 2
+        }),
+        verify(&grown).unwrap().divergence
+    );
+}
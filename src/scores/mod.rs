@@ -0,0 +1,197 @@
+//! A persistent table of high scores, kept as `scores.json` in the data directory or at a path
+//! given by `--scores`. Scores are only compared against scores made under the same rules.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::board::{Board, Rules};
+
+/// Defines the number of scores kept for each set of rules.
+const KEPT: usize = 100;
+
+/// The representation of a finished game recorded in the table.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// The name of the player.
+    pub name: String,
+    /// The final score.
+    pub score: usize,
+    /// The value of the largest cell on the final board.
+    pub max_value: usize,
+    /// The number of movements made, including undos.
+    pub moves: usize,
+    /// The time taken to play the game, in whole seconds.
+    pub seconds: u64,
+    /// The seed the game was played with.
+    pub seed: u64,
+    /// The rules the game was played by.
+    pub rules: Rules,
+    /// When the game finished.
+    #[serde(with = "time::serde::rfc3339")]
+    pub date: OffsetDateTime,
+}
+
+/// The representation of the table of high scores.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Table {
+    /// The recorded games, best first within each set of rules.
+    entries: Vec<Entry>,
+}
+
+impl Entry {
+    /// Creates an entry for a finished game, dated now.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the player
+    /// * `board` - the final board of the game
+    /// * `moves` - the number of movements made
+    /// * `duration` - the time taken to play the game
+    /// * `seed` - the seed the game was played with
+    pub fn new(name: &str, board: &Board, moves: usize, duration: Duration, seed: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            score: board.score(),
+            max_value: board.max_value(),
+            moves,
+            seconds: duration.as_secs(),
+            seed,
+            rules: board.rules().clone(),
+            date: OffsetDateTime::now_utc(),
+        }
+    }
+}
+
+impl Table {
+    /// Records a finished game, returning its rank (from one) among the games played under the
+    /// same rules. Only the best `KEPT` games of each set of rules are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - the game to record
+    pub fn record(&mut self, entry: Entry) -> usize {
+        // ties are ranked below the games which made the score first
+        let rank = self
+            .top(&entry.rules, usize::MAX)
+            .iter()
+            .filter(|other| other.score >= entry.score)
+            .count()
+            + 1;
+
+        let position = self
+            .entries
+            .iter()
+            .position(|other| other.rules == entry.rules && other.score < entry.score)
+            .unwrap_or(self.entries.len());
+        let rules = entry.rules.clone();
+        self.entries.insert(position, entry);
+
+        let mut kept = 0;
+        self.entries.retain(|other| {
+            kept += (other.rules == rules) as usize;
+            other.rules != rules || kept <= KEPT
+        });
+        rank
+    }
+
+    /// Returns the best games played under a set of rules, best first.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - the rules the games were played by
+    /// * `n` - the largest number of games to return
+    pub fn top(&self, rules: &Rules, n: usize) -> Vec<&Entry> {
+        self.entries
+            .iter()
+            .filter(|entry| &entry.rules == rules)
+            .take(n)
+            .collect()
+    }
+
+    /// Returns every set of rules games were played by, in the order they were first recorded.
+    pub fn rules(&self) -> Vec<&Rules> {
+        let mut rules: Vec<&Rules> = Vec::new();
+        for entry in &self.entries {
+            if !rules.contains(&&entry.rules) {
+                rules.push(&entry.rules);
+            }
+        }
+        rules
+    }
+}
+
+/// Formats games as a human readable table, ranked in the order given.
+///
+/// # Arguments
+///
+/// * `entries` - the games to format
+pub fn to_table(entries: &[&Entry]) -> String {
+    let width = entries
+        .iter()
+        .map(|e| e.name.len())
+        .chain([4])
+        .max()
+        .unwrap();
+
+    let mut table = format!(
+        "{:>4}  {:width$}  {:>8}  {:>6}  {:>6}  {:>8}  {:>20}  {}",
+        "rank", "name", "score", "tile", "moves", "time", "seed", "date"
+    );
+    for (rank, e) in entries.iter().enumerate() {
+        table += &format!(
+            "\n{:>4}  {:width$}  {:>8}  {:>6}  {:>6}  {:>5}:{:02}  {:>20}  {}",
+            rank + 1,
+            e.name,
+            e.score,
+            e.max_value,
+            e.moves,
+            e.seconds / 60,
+            e.seconds % 60,
+            e.seed,
+            e.date.date()
+        );
+    }
+    table
+}
+
+/// Runs the `scores` mode: prints the best games of each set of rules, or only of the rules
+/// given by the `--shape`, `--variant`, `--rows`, `--cols`, `--layers`, `--win`, `--undo`,
+/// `--walls`, `--wrap`, `--diagonal` and `--specials` options.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let n = args.get::<usize>("top")?.unwrap_or(10);
+    let path = crate::data::path(&args, "scores", "scores.json")?;
+    let table = crate::data::load::<Table>(&path)?;
+
+    let filtered = [
+        "shape", "variant", "rows", "cols", "layers", "win", "undo", "walls", "wrap", "diagonal",
+        "specials",
+    ]
+    .iter()
+    .any(|name| args.get::<String>(name).is_ok_and(|value| value.is_some()));
+    let rules = match filtered {
+        true => vec![args.rules()?],
+        false => table.rules().into_iter().cloned().collect(),
+    };
+
+    if rules.is_empty() {
+        println!("no scores recorded in {}", path.display());
+    }
+    for (i, rules) in rules.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{}\n", rules);
+        println!("{}", to_table(&table.top(rules, n)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
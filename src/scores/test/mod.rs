@@ -0,0 +1,17 @@
+use super::*;
+
+mod record;
+
+/// Creates an entry with the specified name and score, played by the specified rules.
+fn entry(name: &str, score: usize, rules: &Rules) -> Entry {
+    Entry {
+        name: name.to_string(),
+        score,
+        max_value: 2,
+        moves: 1,
+        seconds: 0,
+        seed: 0,
+        rules: rules.clone(),
+        date: OffsetDateTime::UNIX_EPOCH,
+    }
+}
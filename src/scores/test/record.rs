@@ -0,0 +1,43 @@
+use super::*;
+
+/// Affirm that `Table::record` ranks games against those played under the same rules only.
+#[test]
+fn ranked_per_rules() {
+    let classic = Rules::default();
+    let small = Rules {
+        rows: 3,
+        cols: 3,
+        ..Rules::default()
+    };
+
+    let mut table = Table::default();
+    assert_eq!(1, table.record(entry("a", 100, &classic)));
+    assert_eq!(1, table.record(entry("b", 50, &small)));
+    assert_eq!(1, table.record(entry("c", 200, &classic)));
+    assert_eq!(3, table.record(entry("d", 100, &classic)));
+    assert_eq!(2, table.record(entry("e", 10, &small)));
+
+    let names = |rules| {
+        table
+            .top(rules, 3)
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect::<Vec<&str>>()
+    };
+    assert_eq!(vec!["c", "a", "d"], names(&classic));
+    assert_eq!(vec!["b", "e"], names(&small));
+    assert_eq!(vec![&classic, &small], table.rules());
+}
+
+/// Affirm that `Table::record` keeps only the best `KEPT` games of each set of rules.
+#[test]
+fn bounded() {
+    let rules = Rules::default();
+    let mut table = Table::default();
+    for score in 0..KEPT + 5 {
+        table.record(entry("a", score, &rules));
+    }
+    let top = table.top(&rules, usize::MAX);
+    assert_eq!(KEPT, top.len());
+    assert_eq!(5, top.last().unwrap().score);
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+use serde::Serialize;
+
+/// Defines the largest request body that will be read, in bytes.
+const MAX_BODY: usize = 1 << 20;
+
+/// The representation of an HTTP request.
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    /// The request method, e.g. `GET`.
+    pub method: String,
+    /// The path of the request target, without any query.
+    pub path: String,
+    /// The headers of the request, keyed by their lowercase name.
+    pub headers: HashMap<String, String>,
+    /// The body of the request.
+    pub body: String,
+}
+
+/// The representation of an HTTP response with a JSON body.
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    /// The status code of the response.
+    pub status: u16,
+    /// The JSON body of the response.
+    pub body: String,
+}
+
+impl Request {
+    /// Reads a request from a stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - the stream to read the request from
+    pub fn read(reader: &mut impl BufRead) -> Result<Self, String> {
+        let mut line = String::new();
+        reader.read_line(&mut line).map_err(|e| e.to_string())?;
+
+        let mut words = line.split_whitespace();
+        let (method, target) = match (words.next(), words.next()) {
+            (Some(method), Some(target)) => (method.to_string(), target),
+            _ => return Err(format!("malformed request line: {}", line.trim())),
+        };
+        let path = target.split('?').next().unwrap().to_string();
+
+        let mut headers = HashMap::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).map_err(|e| e.to_string())?;
+            match line.trim_end().split_once(':') {
+                Some((name, value)) => {
+                    headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+                }
+                None => break,
+            }
+        }
+
+        let length = match headers.get("content-length") {
+            Some(length) => length.parse::<usize>().map_err(|e| e.to_string())?,
+            None => 0,
+        };
+        if length > MAX_BODY {
+            return Err(format!("request body of {} bytes is too large", length));
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            method,
+            path,
+            headers,
+            body: String::from_utf8(body).map_err(|e| e.to_string())?,
+        })
+    }
+}
+
+impl Response {
+    /// Creates a response whose body is the value serialized as JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - the status code of the response
+    /// * `value` - the value to serialize
+    pub fn json(status: u16, value: &impl Serialize) -> Self {
+        Self {
+            status,
+            body: serde_json::to_string(value).unwrap(),
+        }
+    }
+
+    /// Creates a response whose body is a JSON object holding an error message.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - the status code of the response
+    /// * `message` - the error message
+    pub fn error(status: u16, message: &str) -> Self {
+        Self::json(status, &serde_json::json!({ "error": message }))
+    }
+
+    /// Writes the response to a stream. Cross-origin requests are allowed, so that a frontend
+    /// served from elsewhere may use the server, and the connection is closed afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - the stream to write the response to
+    pub fn write_to(&self, mut writer: impl Write) -> std::io::Result<()> {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\n\
+             Content-Type: application/json\r\n\
+             Content-Length: {}\r\n\
+             Access-Control-Allow-Origin: *\r\n\
+             Access-Control-Allow-Methods: GET, POST, OPTIONS\r\n\
+             Access-Control-Allow-Headers: Content-Type\r\n\
+             Connection: close\r\n\
+             \r\n\
+             {}",
+            self.status,
+            reason(self.status),
+            self.body.len(),
+            self.body
+        )?;
+        writer.flush()
+    }
+}
+
+/// Returns the reason phrase of a status code.
+///
+/// # Arguments
+///
+/// * `status` - the status code
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        _ => "Internal Server Error",
+    }
+}
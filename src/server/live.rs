@@ -0,0 +1,284 @@
+//! Live play and spectating over WebSocket.
+//!
+//! A connection is opened by upgrading a request to `/games/<id>/play`, to play the game, or to
+//! `/games/<id>/watch`, to spectate it. Every message is a JSON object holding the schema version
+//! `v` (currently `1`) and a `type`.
+//!
+//! # Server to client
+//!
+//! * `{"v": 1, "type": "snapshot", "state": <state>}` - sent once, as soon as the connection
+//!   opens, so that late joiners see the game as it stands
+//! * `{"v": 1, "type": "update", "move": <move>, "events": [<event>...], "state": <state>}` -
+//!   sent to every connection after each move made on the game (including moves made over HTTP),
+//!   where `<move>` is a direction or `undo`
+//! * `{"v": 1, "type": "error", "message": <message>}` - sent to a client whose message was
+//!   rejected
+//!
+//! A `<state>` is the same object returned by the HTTP endpoints. An `<event>` is one of
+//! `{"type": "slide", "from": [row, col], "to": [row, col]}`,
+//! `{"type": "merge", "from": [row, col], "into": [row, col], "cell": <value>}`,
+//! `{"type": "clear", "from": [row, col], "into": [row, col]}` or
+//! `{"type": "spawn", "at": [row, col], "cell": <value>}`, where the `<value>` of a special cell is
+//! the name of its kind, and a value too large for 64 bits is written as a string of its digits.
+//! An undo has no events.
+//!
+//! # Client to server
+//!
+//! * `{"v": 1, "type": "move", "direction": <direction>}` - shifts the board (players only)
+//! * `{"v": 1, "type": "undo"}` - undoes the last shift (players only)
+//!
+//! Either message also gives the `player` making it, in a cooperative game.
+
+use std::net::TcpStream;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tungstenite::protocol::Role;
+use tungstenite::{Message, WebSocket};
+
+use super::{Request, Response, Server, State};
+use crate::board::Event;
+use crate::{Direction, Move};
+
+/// Defines the version of the message schema.
+pub const VERSION: u32 = 1;
+
+/// Defines how long to wait for a message from a client before checking for updates to send it.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// The representation of a message sent to a client.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Outgoing<'a> {
+    Snapshot {
+        state: State<'a>,
+    },
+    Update {
+        #[serde(rename = "move")]
+        mov: String,
+        events: &'a [Event],
+        state: State<'a>,
+    },
+    Error {
+        message: &'a str,
+    },
+}
+
+/// The representation of a message received from a client.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Incoming {
+    Move {
+        direction: Direction,
+        #[serde(default)]
+        player: Option<String>,
+    },
+    Undo {
+        #[serde(default)]
+        player: Option<String>,
+    },
+}
+
+/// The representation of the envelope every message is wrapped in, holding the schema version.
+#[derive(Deserialize, Serialize)]
+struct Envelope<T> {
+    v: u32,
+    #[serde(flatten)]
+    message: T,
+}
+
+/// Serializes a message to a client.
+///
+/// # Arguments
+///
+/// * `message` - the message to serialize
+fn encode(message: Outgoing) -> String {
+    serde_json::to_string(&Envelope {
+        v: VERSION,
+        message,
+    })
+    .unwrap()
+}
+
+/// Parses a message from a client, checking that it is of the current schema version.
+///
+/// # Arguments
+///
+/// * `text` - the text of the message
+fn decode(text: &str) -> Result<Incoming, String> {
+    let envelope =
+        serde_json::from_str::<Envelope<serde_json::Value>>(text).map_err(|e| e.to_string())?;
+    if envelope.v != VERSION {
+        return Err(format!("unsupported schema version {}", envelope.v));
+    }
+    serde_json::from_value(envelope.message).map_err(|e| e.to_string())
+}
+
+/// Returns the message sent to every client after a move.
+///
+/// # Arguments
+///
+/// * `mov` - the move that was made
+/// * `events` - the events that followed the move
+/// * `state` - the state of the game after the move
+pub(super) fn update(mov: Move, events: &[Event], state: &State) -> String {
+    encode(Outgoing::Update {
+        mov: mov.to_string(),
+        events,
+        state: state.clone(),
+    })
+}
+
+impl Server {
+    /// Sends a message to every live connection watching a game, forgetting those that closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the session ID of the game
+    /// * `message` - the message to send
+    pub(super) fn broadcast(&self, id: u64, message: &str) {
+        if let Some(senders) = self.watchers.lock().unwrap().get_mut(&id) {
+            senders.retain(|sender| sender.send(message.to_string()).is_ok());
+        }
+    }
+
+    /// Registers a new live connection to a game, returning the snapshot to send it first and the
+    /// receiver of every following update. As moves are performed while the games are locked,
+    /// no update can be missed between the snapshot and the registration.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the session ID of the game
+    fn watch(&self, id: u64) -> Option<(String, Receiver<String>)> {
+        let games = self.games.lock().unwrap();
+        let teams = self.teams.lock().unwrap();
+        let board = games.get(&id)?;
+
+        let (sender, receiver) = mpsc::channel();
+        self.watchers
+            .lock()
+            .unwrap()
+            .entry(id)
+            .or_default()
+            .push(sender);
+
+        let snapshot = encode(Outgoing::Snapshot {
+            state: Server::state(id, board, teams.get(&id)),
+        });
+        Some((snapshot, receiver))
+    }
+
+    /// Handles a message received from a client, returning the error to send back, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the session ID of the game
+    /// * `player` - whether the client may make moves
+    /// * `text` - the text of the message
+    fn receive(&self, id: u64, player: bool, text: &str) -> Option<String> {
+        let (mov, name) = match decode(text) {
+            Ok(_) if !player => return Some("spectators cannot make moves".to_string()),
+            Ok(Incoming::Move { direction, player }) => (Move::Shift(direction), player),
+            Ok(Incoming::Undo { player }) => (Move::Undo, player),
+            Err(e) => return Some(e),
+        };
+        // on success, the update reaches this client along with every other
+        self.perform(id, mov, name.as_deref())
+            .err()
+            .map(|(_, message)| message)
+    }
+
+    /// Upgrades a connection to a WebSocket and serves it until it closes, replying to a closing
+    /// client before closing the connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - the connection to upgrade
+    /// * `req` - the request to upgrade the connection
+    /// * `read_ahead` - the bytes read from the connection after the request, which begin the
+    ///   stream of frames
+    pub(super) fn live(&self, stream: TcpStream, req: &Request, read_ahead: Vec<u8>) {
+        let segments = req
+            .path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<&str>>();
+        let (id, player) = match segments.as_slice() {
+            ["games", id, "play"] => (id.parse::<u64>().ok(), true),
+            ["games", id, "watch"] => (id.parse::<u64>().ok(), false),
+            _ => (None, false),
+        };
+        let key = match req.headers.get("sec-websocket-key") {
+            Some(key) => key,
+            None => {
+                let _ = Response::error(400, "missing websocket key").write_to(&stream);
+                return;
+            }
+        };
+        let (id, (snapshot, updates)) = match id.and_then(|id| Some((id, self.watch(id)?))) {
+            Some(watch) => watch,
+            None => {
+                let _ = Response::error(404, "no such game").write_to(&stream);
+                return;
+            }
+        };
+
+        let accept = tungstenite::handshake::derive_accept_key(key.as_bytes());
+        let handshake = format!(
+            "HTTP/1.1 101 Switching Protocols\r\n\
+             Upgrade: websocket\r\n\
+             Connection: Upgrade\r\n\
+             Sec-WebSocket-Accept: {}\r\n\
+             \r\n",
+            accept
+        );
+        if std::io::Write::write_all(&mut &stream, handshake.as_bytes()).is_err()
+            || stream.set_read_timeout(Some(POLL_INTERVAL)).is_err()
+        {
+            return;
+        }
+
+        let mut socket = WebSocket::from_partially_read(stream, read_ahead, Role::Server, None);
+        if socket.send(Message::Text(snapshot)).is_err() {
+            return;
+        }
+
+        loop {
+            match socket.read() {
+                Ok(Message::Text(text)) => {
+                    if let Some(message) = self.receive(id, player, &text) {
+                        let error = encode(Outgoing::Error { message: &message });
+                        if socket.send(Message::Text(error)).is_err() {
+                            return;
+                        }
+                    }
+                }
+                Ok(Message::Close(_)) => {
+                    // the reply to the close is queued by reading it, and must still be sent
+                    let _ = socket.flush();
+                    return;
+                }
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(e))
+                    if matches!(
+                        e.kind(),
+                        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                    ) => {}
+                Err(_) => return,
+            }
+
+            loop {
+                match updates.try_recv() {
+                    Ok(message) => {
+                        if socket.send(Message::Text(message)).is_err() {
+                            return;
+                        }
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => return,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,438 @@
+//! A local HTTP server hosting many games at once, addressed by session ID. Every request and
+//! response body is JSON.
+//!
+//! * `POST /games` - creates a game; the body may give a `seed` and the `rules` (including the
+//!   `rows` and `cols` of the board, the coordinates of any `walls` and whether it should
+//!   `wrap` around), e.g.
+//!   `{"seed": 7, "rules": {"rows": 5, "cols": 5, "walls": [[2, 2]]}}`
+//! * `GET /games` - lists every game
+//! * `GET /games/<id>` - returns the state of a game
+//! * `POST /games/<id>/move` - shifts the board of a game, given a body such as
+//!   `{"direction": "up"}`
+//! * `POST /games/<id>/undo` - undoes the last shift of a game
+//!
+//! A cooperative game is created by giving the names of its `players` in turn order, and may also
+//! limit the `undos` of the whole team and give each turn a time limit of `turn_time` seconds,
+//! e.g. `{"players": ["alice", "bob"], "undos": 3, "turn_time": 30}`. Each move or undo of such a
+//! game must then give the `player` making it, e.g. `{"direction": "up", "player": "alice"}`, and
+//! is rejected unless it is their turn. The teams of cooperative games are not saved.
+//!
+//! The state of a game is returned as an object holding its `id`, `rules`, `grid` (a list of
+//! rows, with empty cells and walls valued at zero, coordinates lying off the board (such as the
+//! corners of a hexagonal board) given as `null`, special cells given by the names of their kinds
+//! and values too large for 64 bits given as strings), `score`, `status`, `legal_moves` and
+//! `can_undo`, as well as, for a cooperative game, its `team`: the `players`, the player whose
+//! `turn` it is, the `undos_left` and `turn_remaining` (in seconds) if limited, every `move` made
+//! with the `player` who made it, and the `stats` of each player.
+//!
+//! Games can also be played and watched live over WebSocket; see the `live` module.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::BufReader;
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::blitz::SystemClock;
+use crate::board::{Board, Rules, Status};
+use crate::coop::Team;
+use crate::stats::Game;
+use crate::{Direction, Move};
+
+mod http;
+mod live;
+
+pub use http::{Request, Response};
+
+/// The representation of the state of a game as returned by the server.
+#[derive(Clone, Serialize)]
+struct State<'a> {
+    id: u64,
+    rules: &'a Rules,
+    /// The cells of the board, row by row, with empty cells written as zero and coordinates lying
+    /// off the board written as `null`.
+    grid: Vec<Vec<serde_json::Value>>,
+    score: usize,
+    status: Status,
+    legal_moves: Vec<Direction>,
+    can_undo: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    team: Option<TeamState<'a>>,
+}
+
+/// The representation of the team of a cooperative game as returned by the server.
+#[derive(Clone, Serialize)]
+struct TeamState<'a> {
+    players: &'a [String],
+    turn: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    undos_left: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    turn_remaining: Option<f64>,
+    /// Every movement made, with the name of the player who made it.
+    moves: Vec<Attributed<'a>>,
+    /// The statistics of the movements of each player, keyed by their name.
+    stats: BTreeMap<&'a str, &'a Game>,
+}
+
+/// The representation of a movement made by a player of a cooperative game.
+#[derive(Clone, Serialize)]
+struct Attributed<'a> {
+    player: &'a str,
+    #[serde(rename = "move")]
+    mov: String,
+}
+
+/// The representation of the body of a request to create a game.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct NewGame {
+    seed: Option<u64>,
+    rules: Rules,
+    /// The names of the players of a cooperative game, in turn order.
+    players: Vec<String>,
+    /// The number of undos the team of a cooperative game may perform, if limited.
+    undos: Option<usize>,
+    /// The time limit of each turn of a cooperative game in seconds, if turns are timed.
+    turn_time: Option<u64>,
+}
+
+/// The representation of the body of a request to shift the board of a game.
+#[derive(Deserialize)]
+struct Shift {
+    direction: Direction,
+    /// The player making the shift, in a cooperative game.
+    #[serde(default)]
+    player: Option<String>,
+}
+
+/// The representation of the body of a request to undo the last shift of a game.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Undo {
+    /// The player making the undo, in a cooperative game.
+    player: Option<String>,
+}
+
+/// The representation of a server hosting many games.
+pub struct Server {
+    /// The games, keyed by session ID.
+    games: Mutex<BTreeMap<u64, Board>>,
+    /// The teams playing cooperative games, keyed by the session ID of their game. They are
+    /// always locked after the games.
+    teams: Mutex<HashMap<u64, Team<SystemClock>>>,
+    /// The directory each game is saved to after it changes, if any.
+    dir: Option<PathBuf>,
+    /// The senders of the messages to each live connection, keyed by the session ID of the game
+    /// the connection watches.
+    watchers: Mutex<HashMap<u64, Vec<Sender<String>>>>,
+}
+
+impl Server {
+    /// Creates a server, restoring every game previously saved to the directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - the directory each game is saved to, if any
+    pub fn new(dir: Option<PathBuf>) -> Result<Self, String> {
+        let mut games = BTreeMap::new();
+
+        if let Some(dir) = &dir {
+            std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+            for entry in std::fs::read_dir(dir).map_err(|e| e.to_string())? {
+                let path = entry.map_err(|e| e.to_string())?.path();
+                let id = match path.extension().and_then(|ext| ext.to_str()) {
+                    Some("json") => path.file_stem().and_then(|s| s.to_str()?.parse().ok()),
+                    _ => None,
+                };
+                if let Some(id) = id {
+                    let json = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+                    let board = serde_json::from_str::<Board>(&json)
+                        .map_err(|e| format!("{}: {}", path.display(), e))?;
+                    games.insert(id, board);
+                }
+            }
+        }
+
+        Ok(Self {
+            games: Mutex::new(games),
+            teams: Mutex::new(HashMap::new()),
+            dir,
+            watchers: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Saves a game to the directory, if there is one. The game is written to a temporary file
+    /// which then replaces the previous save, so that a save is never left partially written.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the session ID of the game
+    /// * `board` - the board of the game
+    fn save(&self, id: u64, board: &Board) -> Result<(), String> {
+        if let Some(dir) = &self.dir {
+            let path = dir.join(format!("{}.json", id));
+            let temp = dir.join(format!("{}.json.tmp", id));
+            std::fs::write(&temp, serde_json::to_string(board).unwrap())
+                .and_then(|_| std::fs::rename(&temp, &path))
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the state of a game.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the session ID of the game
+    /// * `board` - the board of the game
+    /// * `team` - the team playing the game, if it is cooperative
+    fn state<'a>(id: u64, board: &'a Board, team: Option<&'a Team<SystemClock>>) -> State<'a> {
+        let team = team.map(|team| TeamState {
+            players: team.players(),
+            turn: &team.players()[team.turn()],
+            undos_left: team.undos_left(),
+            turn_remaining: team.turn_remaining().map(|time| time.as_secs_f64()),
+            moves: team
+                .log()
+                .iter()
+                .map(|turn| Attributed {
+                    player: &team.players()[turn.player],
+                    mov: turn.mov.to_string(),
+                })
+                .collect(),
+            stats: team
+                .players()
+                .iter()
+                .enumerate()
+                .map(|(idx, name)| (name.as_str(), team.tally(idx)))
+                .collect(),
+        });
+        State {
+            id,
+            rules: board.rules(),
+            grid: board
+                .cells()
+                .into_iter()
+                .enumerate()
+                .map(|(row, cells)| {
+                    cells
+                        .into_iter()
+                        .enumerate()
+                        .map(|(col, cell)| match cell {
+                            _ if !board.rules().contains((row, col)) => serde_json::Value::Null,
+                            Some(cell) => serde_json::json!(cell),
+                            None => serde_json::json!(0),
+                        })
+                        .collect()
+                })
+                .collect(),
+            score: board.score(),
+            status: board.status(),
+            legal_moves: board.legal_moves(),
+            can_undo: board.can_undo(),
+            team,
+        }
+    }
+
+    /// Handles a request, returning the response to send.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the request to handle
+    pub fn handle(&self, req: &Request) -> Response {
+        let mut games = self.games.lock().unwrap();
+        let mut teams = self.teams.lock().unwrap();
+        for team in teams.values_mut() {
+            team.expire();
+        }
+        let segments = req
+            .path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<&str>>();
+
+        let (id, action) = match (req.method.as_str(), segments.as_slice()) {
+            ("OPTIONS", _) => {
+                return Response {
+                    status: 204,
+                    body: String::new(),
+                }
+            }
+            ("GET", ["games"]) => {
+                let states = games
+                    .iter()
+                    .map(|(id, board)| Server::state(*id, board, teams.get(id)))
+                    .collect::<Vec<State>>();
+                return Response::json(200, &states);
+            }
+            ("POST", ["games"]) => {
+                let new = match req.body.trim() {
+                    "" => NewGame::default(),
+                    body => match serde_json::from_str::<NewGame>(body) {
+                        Ok(new) => new,
+                        Err(e) => return Response::error(400, &e.to_string()),
+                    },
+                };
+                if new.rules.validate().is_err() {
+                    return Response::error(400, "invalid rules");
+                }
+                let team = match new.players.is_empty() {
+                    true => None,
+                    false => match Team::new(
+                        new.players,
+                        new.undos,
+                        new.turn_time.map(std::time::Duration::from_secs),
+                        SystemClock::default(),
+                    ) {
+                        Ok(team) => Some(team),
+                        Err(e) => return Response::error(400, &e),
+                    },
+                };
+
+                let id = games.keys().next_back().map_or(0, |id| id + 1);
+                let board = Board::with_rules(new.rules, new.seed.unwrap_or_else(rand::random));
+                if let Err(e) = self.save(id, &board) {
+                    return Response::error(500, &e);
+                }
+                let response = Response::json(201, &Server::state(id, &board, team.as_ref()));
+                games.insert(id, board);
+                if let Some(team) = team {
+                    teams.insert(id, team);
+                }
+                return response;
+            }
+            (method, ["games", id, action @ ..]) => match id.parse::<u64>() {
+                Ok(id) if games.contains_key(&id) => (id, (method, action)),
+                _ => return Response::error(404, "no such game"),
+            },
+            _ => return Response::error(404, "no such resource"),
+        };
+
+        let (mov, player) = match action {
+            ("GET", []) => {
+                return Response::json(200, &Server::state(id, &games[&id], teams.get(&id)))
+            }
+            ("POST", ["move"]) => match serde_json::from_str::<Shift>(&req.body) {
+                Ok(shift) => (Move::Shift(shift.direction), shift.player),
+                Err(e) => return Response::error(400, &e.to_string()),
+            },
+            ("POST", ["undo"]) => match req.body.trim() {
+                "" => (Move::Undo, None),
+                body => match serde_json::from_str::<Undo>(body) {
+                    Ok(undo) => (Move::Undo, undo.player),
+                    Err(e) => return Response::error(400, &e.to_string()),
+                },
+            },
+            (_, [] | ["move"] | ["undo"]) => return Response::error(405, "method not allowed"),
+            _ => return Response::error(404, "no such resource"),
+        };
+
+        drop(teams);
+        drop(games);
+        match self.perform(id, mov, player.as_deref()) {
+            Ok(state) => Response {
+                status: 200,
+                body: state,
+            },
+            Err((status, message)) => Response::error(status, &message),
+        }
+    }
+
+    /// Performs a move on a game, saving it and sending the update to every live connection
+    /// watching it. The move of a cooperative game must be made by the player whose turn it is.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the session ID of the game
+    /// * `mov` - the move to perform
+    /// * `player` - the name of the player making the move, in a cooperative game
+    ///
+    /// # Returns
+    /// * `Ok(String)` - the state of the game after the move, as JSON
+    /// * `Err((u16, String))` - the status code and message describing why the move was not
+    ///   performed
+    fn perform(&self, id: u64, mov: Move, player: Option<&str>) -> Result<String, (u16, String)> {
+        let mut games = self.games.lock().unwrap();
+        let mut teams = self.teams.lock().unwrap();
+        let board = games
+            .get_mut(&id)
+            .ok_or((404, "no such game".to_string()))?;
+        let cannot = || (409, "the move cannot be performed".to_string());
+
+        let events = match teams.get_mut(&id) {
+            Some(team) => {
+                let name = player.ok_or((400, "a player must be given".to_string()))?;
+                let idx = team
+                    .player(name)
+                    .ok_or((400, format!("no such player: {}", name)))?;
+                team.expire();
+                if team.turn() != idx {
+                    return Err((409, format!("it is not {}'s turn", name)));
+                }
+                team.play(board, idx, mov).map_err(|_| cannot())?
+            }
+            None => board.movement(mov).map_err(|_| cannot())?,
+        };
+        self.save(id, board).map_err(|e| (500, e))?;
+
+        let state = Server::state(id, board, teams.get(&id));
+        self.broadcast(id, &live::update(mov, &events, &state));
+        Ok(serde_json::to_string(&state).unwrap())
+    }
+
+    /// Reads a request from a connection and writes back the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - the connection to serve
+    fn serve(&self, stream: TcpStream) {
+        let mut reader = BufReader::new(&stream);
+        let req = match Request::read(&mut reader) {
+            Ok(req) => req,
+            Err(e) => {
+                let _ = Response::error(400, &e).write_to(&stream);
+                return;
+            }
+        };
+        // a client may send its first frames along with the request, which were read ahead
+        let read_ahead = reader.buffer().to_vec();
+        drop(reader);
+
+        let upgrade = req.headers.get("upgrade");
+        match upgrade.is_some_and(|u| u.eq_ignore_ascii_case("websocket")) {
+            true => self.live(stream, &req, read_ahead),
+            false => {
+                let _ = self.handle(&req).write_to(&stream);
+            }
+        }
+    }
+}
+
+/// Runs the `serve` mode: hosts games over HTTP on localhost, serving each connection on its own
+/// thread.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let port = args.get::<u16>("port")?.unwrap_or(2048);
+    let server = Arc::new(Server::new(args.get::<PathBuf>("save-dir")?)?);
+
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .map_err(|e| format!("port {}: {}", port, e))?;
+    println!("listening on http://{}", listener.local_addr().unwrap());
+
+    for stream in listener.incoming().map_while(Result::ok) {
+        let server = Arc::clone(&server);
+        std::thread::spawn(move || server.serve(stream));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,59 @@
+use super::*;
+
+/// Affirm that a cooperative game is created with its team, and that its players must move in
+/// turn, each movement being attributed to its player.
+#[test]
+fn turns() {
+    let server = Server::new(None).unwrap();
+    let (status, created) = send(
+        &server,
+        "POST",
+        "/games",
+        r#"{"seed": 2, "players": ["alice", "bob"], "undos": 1}"#,
+    );
+    assert_eq!(201, status);
+    assert_eq!(
+        serde_json::json!(["alice", "bob"]),
+        created["team"]["players"]
+    );
+    assert_eq!("alice", created["team"]["turn"]);
+    assert_eq!(1, created["team"]["undos_left"]);
+
+    let dir = created["legal_moves"][0].as_str().unwrap().to_string();
+    let shift = |player: &str| format!(r#"{{"direction": "{}", "player": "{}"}}"#, dir, player);
+    assert_eq!(409, send(&server, "POST", "/games/0/move", &shift("bob")).0);
+    assert_eq!(
+        400,
+        send(&server, "POST", "/games/0/move", &shift("carol")).0
+    );
+    let plain = format!(r#"{{"direction": "{}"}}"#, dir);
+    assert_eq!(400, send(&server, "POST", "/games/0/move", &plain).0);
+
+    let (status, moved) = send(&server, "POST", "/games/0/move", &shift("alice"));
+    assert_eq!(200, status);
+    assert_eq!("bob", moved["team"]["turn"]);
+    assert_eq!(
+        serde_json::json!([{"player": "alice", "move": dir}]),
+        moved["team"]["moves"]
+    );
+    assert_eq!(1, moved["team"]["stats"]["alice"]["moves"]);
+
+    let (status, undone) = send(&server, "POST", "/games/0/undo", r#"{"player": "bob"}"#);
+    assert_eq!(200, status);
+    assert_eq!("alice", undone["team"]["turn"]);
+    assert_eq!(0, undone["team"]["undos_left"]);
+    assert_eq!(created["grid"], undone["grid"]);
+}
+
+/// Affirm that a cooperative game is not created for an invalid team or turn time, and that other
+/// games have no team.
+#[test]
+fn invalid() {
+    let server = Server::new(None).unwrap();
+    let body = r#"{"players": ["alice", "alice"]}"#;
+    assert_eq!(400, send(&server, "POST", "/games", body).0);
+    let body = r#"{"players": ["alice", "bob"], "turn_time": 0}"#;
+    assert_eq!(400, send(&server, "POST", "/games", body).0);
+    let (_, created) = send(&server, "POST", "/games", "");
+    assert_eq!(None, created.get("team"));
+}
@@ -0,0 +1,120 @@
+use super::*;
+
+/// Affirm that a game is created with the requested size and returned with its full state.
+#[test]
+fn create() {
+    let server = Server::new(None).unwrap();
+    let (status, state) = send(
+        &server,
+        "POST",
+        "/games",
+        r#"{"seed": 1, "rules": {"rows": 3, "cols": 5}}"#,
+    );
+
+    assert_eq!(201, status);
+    assert_eq!(0, state["id"]);
+    assert_eq!(3, state["grid"].as_array().unwrap().len());
+    assert_eq!(5, state["grid"][0].as_array().unwrap().len());
+    assert_eq!(0, state["score"]);
+    assert_eq!("playing", state["status"]);
+    assert_eq!(false, state["can_undo"]);
+    assert!(!state["legal_moves"].as_array().unwrap().is_empty());
+}
+
+/// Affirm that games created with the same seed are identical.
+#[test]
+fn create_seeded() {
+    let server = Server::new(None).unwrap();
+    let (_, a) = send(&server, "POST", "/games", r#"{"seed": 5}"#);
+    let (_, b) = send(&server, "POST", "/games", r#"{"seed": 5}"#);
+    assert_eq!(a["grid"], b["grid"]);
+    assert_eq!(1, b["id"]);
+}
+
+/// Affirm that games with invalid rules or malformed bodies are not created.
+#[test]
+fn create_invalid() {
+    let server = Server::new(None).unwrap();
+    assert_eq!(
+        400,
+        send(&server, "POST", "/games", r#"{"rules": {"rows": 0}}"#).0
+    );
+    assert_eq!(400, send(&server, "POST", "/games", "{").0);
+    assert_eq!(200, send(&server, "GET", "/games", "").0);
+    assert_eq!(serde_json::json!([]), send(&server, "GET", "/games", "").1);
+}
+
+/// Affirm that a legal move and its undo are applied to the game, and reported in its state.
+#[test]
+fn move_and_undo() {
+    let server = Server::new(None).unwrap();
+    let (_, created) = send(&server, "POST", "/games", r#"{"seed": 2}"#);
+    let dir = created["legal_moves"][0].as_str().unwrap().to_string();
+
+    let body = format!(r#"{{"direction": "{}"}}"#, dir);
+    let (status, moved) = send(&server, "POST", "/games/0/move", &body);
+    assert_eq!(200, status);
+    assert_eq!(true, moved["can_undo"]);
+    assert_ne!(created["grid"], moved["grid"]);
+
+    let (status, undone) = send(&server, "POST", "/games/0/undo", "");
+    assert_eq!(200, status);
+    assert_eq!(created["grid"], undone["grid"]);
+    assert_eq!(undone, send(&server, "GET", "/games/0", "").1);
+}
+
+/// Affirm that moves which cannot be performed are rejected as conflicts.
+#[test]
+fn illegal() {
+    let server = Server::new(None).unwrap();
+    send(&server, "POST", "/games", "");
+    assert_eq!(409, send(&server, "POST", "/games/0/undo", "").0);
+    assert_eq!(
+        400,
+        send(
+            &server,
+            "POST",
+            "/games/0/move",
+            r#"{"direction": "sideways"}"#
+        )
+        .0
+    );
+}
+
+/// Affirm that unknown games, resources and methods are reported.
+#[test]
+fn not_found() {
+    let server = Server::new(None).unwrap();
+    send(&server, "POST", "/games", "");
+    assert_eq!(404, send(&server, "GET", "/games/1", "").0);
+    assert_eq!(404, send(&server, "GET", "/games/zero", "").0);
+    assert_eq!(404, send(&server, "GET", "/scores", "").0);
+    assert_eq!(405, send(&server, "GET", "/games/0/move", "").0);
+}
+
+/// Affirm that every game is listed.
+#[test]
+fn list() {
+    let server = Server::new(None).unwrap();
+    send(&server, "POST", "/games", "");
+    send(&server, "POST", "/games", "");
+    let (_, list) = send(&server, "GET", "/games", "");
+    assert_eq!(
+        vec![0, 1],
+        list.as_array()
+            .unwrap()
+            .iter()
+            .map(|state| state["id"].as_u64().unwrap())
+            .collect::<Vec<u64>>()
+    );
+}
+
+/// Affirm that a game allowing more undos than any rules may is rejected, leaving the server able
+/// to handle the requests that follow.
+#[test]
+fn create_unbounded_undos() {
+    let server = Server::new(None).unwrap();
+    let body = format!(r#"{{"rules": {{"undo_limit": {}}}}}"#, u64::MAX);
+    assert_eq!(400, send(&server, "POST", "/games", &body).0);
+    assert_eq!(201, send(&server, "POST", "/games", "").0);
+}
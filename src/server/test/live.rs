@@ -0,0 +1,164 @@
+use std::net::TcpListener;
+
+use tungstenite::{Message, WebSocket};
+
+use super::*;
+
+/// Starts a server hosting one seeded game on an ephemeral port, returning its address.
+fn start() -> String {
+    let server = Arc::new(Server::new(None).unwrap());
+    send(&server, "POST", "/games", r#"{"seed": 3}"#);
+
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    std::thread::spawn(move || {
+        for stream in listener.incoming().map_while(Result::ok) {
+            let server = Arc::clone(&server);
+            std::thread::spawn(move || server.serve(stream));
+        }
+    });
+    addr
+}
+
+/// Opens a live connection to a path of the server.
+fn connect(addr: &str, path: &str) -> WebSocket<TcpStream> {
+    let stream = TcpStream::connect(addr).unwrap();
+    tungstenite::client(format!("ws://{}{}", addr, path), stream)
+        .unwrap()
+        .0
+}
+
+/// Receives the next message of a live connection, parsed as JSON.
+fn receive(socket: &mut WebSocket<TcpStream>) -> serde_json::Value {
+    match socket.read().unwrap() {
+        Message::Text(text) => serde_json::from_str(&text).unwrap(),
+        message => panic!("unexpected message: {:?}", message),
+    }
+}
+
+/// Affirm that players and spectators receive a snapshot on joining, then every update.
+#[test]
+fn play_and_watch() {
+    let addr = start();
+    let mut player = connect(&addr, "/games/0/play");
+    let mut spectator = connect(&addr, "/games/0/watch");
+
+    let snapshot = receive(&mut player);
+    assert_eq!(1, snapshot["v"]);
+    assert_eq!("snapshot", snapshot["type"]);
+    assert_eq!(snapshot, receive(&mut spectator));
+
+    let direction = snapshot["state"]["legal_moves"][0].as_str().unwrap();
+    let text = format!(
+        r#"{{"v": 1, "type": "move", "direction": "{}"}}"#,
+        direction
+    );
+    player.send(Message::Text(text)).unwrap();
+
+    let update = receive(&mut player);
+    assert_eq!("update", update["type"]);
+    assert_eq!(direction, update["move"]);
+    assert_eq!(
+        "spawn",
+        update["events"].as_array().unwrap().last().unwrap()["type"]
+    );
+    assert_eq!(true, update["state"]["can_undo"]);
+    assert_eq!(update, receive(&mut spectator));
+
+    // a late joiner sees the game as it stands
+    let mut late = connect(&addr, "/games/0/watch");
+    assert_eq!(update["state"], receive(&mut late)["state"]);
+}
+
+/// Affirm that spectators and malformed messages are answered with an error.
+#[test]
+fn rejected() {
+    let addr = start();
+    let mut spectator = connect(&addr, "/games/0/watch");
+    receive(&mut spectator);
+    let undo = r#"{"v": 1, "type": "undo"}"#.to_string();
+    spectator.send(Message::Text(undo)).unwrap();
+    let error = receive(&mut spectator);
+    assert_eq!("error", error["type"]);
+    assert_eq!("spectators cannot make moves", error["message"]);
+
+    let mut player = connect(&addr, "/games/0/play");
+    receive(&mut player);
+    let future = r#"{"v": 2, "type": "undo"}"#.to_string();
+    player.send(Message::Text(future)).unwrap();
+    assert_eq!("error", receive(&mut player)["type"]);
+
+    // nothing to undo yet
+    let undo = r#"{"v": 1, "type": "undo"}"#.to_string();
+    player.send(Message::Text(undo)).unwrap();
+    assert_eq!("error", receive(&mut player)["type"]);
+}
+
+/// Affirm that live connections to a missing game are refused.
+#[test]
+fn missing_game() {
+    let addr = start();
+    let stream = TcpStream::connect(&addr).unwrap();
+    assert!(tungstenite::client(format!("ws://{}/games/9/play", addr), stream).is_err());
+}
+
+/// Affirm that a client closing its connection receives the reply completing the close.
+#[test]
+fn close() {
+    let addr = start();
+    let mut player = connect(&addr, "/games/0/play");
+    receive(&mut player);
+
+    player.close(None).unwrap();
+    loop {
+        match player.read() {
+            Ok(_) => {}
+            Err(tungstenite::Error::ConnectionClosed) => break,
+            Err(e) => panic!("the close was not completed: {}", e),
+        }
+    }
+}
+
+/// Affirm that a frame sent along with the request to upgrade the connection is not lost.
+#[test]
+fn frame_with_request() {
+    let addr = start();
+    let text = r#"{"v": 1, "type": "undo"}"#;
+
+    // a masked text frame, whose mask of zeros leaves its payload as it is
+    let mut bytes = format!(
+        "GET /games/0/play HTTP/1.1\r\n\
+         Host: {}\r\n\
+         Upgrade: websocket\r\n\
+         Connection: Upgrade\r\n\
+         Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+         Sec-WebSocket-Version: 13\r\n\
+         \r\n",
+        addr
+    )
+    .into_bytes();
+    bytes.extend([0x81, 0x80 | text.len() as u8, 0, 0, 0, 0]);
+    bytes.extend(text.as_bytes());
+    let mut stream = TcpStream::connect(&addr).unwrap();
+    stream
+        .set_read_timeout(Some(std::time::Duration::from_secs(5)))
+        .unwrap();
+    std::io::Write::write_all(&mut stream, &bytes).unwrap();
+
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut line = String::new();
+    while line != "\r\n" {
+        line.clear();
+        std::io::BufRead::read_line(&mut reader, &mut line).unwrap();
+    }
+    let mut player = WebSocket::from_partially_read(
+        stream,
+        reader.buffer().to_vec(),
+        tungstenite::protocol::Role::Client,
+        None,
+    );
+
+    // the undo cannot be performed on a new game, so the frame is answered with an error
+    assert_eq!("snapshot", receive(&mut player)["type"]);
+    assert_eq!("error", receive(&mut player)["type"]);
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+mod coop;
+mod handle;
+mod live;
+mod new;
+mod read;
+
+/// Creates a request with the specified method, path and body.
+fn request(method: &str, path: &str, body: &str) -> Request {
+    Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        headers: Default::default(),
+        body: body.to_string(),
+    }
+}
+
+/// Handles a request, returning the status and the body parsed as JSON.
+fn send(server: &Server, method: &str, path: &str, body: &str) -> (u16, serde_json::Value) {
+    let response = server.handle(&request(method, path, body));
+    (
+        response.status,
+        serde_json::from_str(&response.body).unwrap(),
+    )
+}
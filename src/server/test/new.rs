@@ -0,0 +1,32 @@
+use super::*;
+
+/// Affirm that games saved to the directory are restored by a new server, and that the
+/// restored games continue exactly as the originals would have.
+#[test]
+fn restores_saved_games() {
+    let dir = std::env::temp_dir().join(format!("rust2048-server-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let server = Server::new(Some(dir.clone())).unwrap();
+    let (_, created) = send(&server, "POST", "/games", r#"{"seed": 3}"#);
+    let dir_name = created["legal_moves"][0].as_str().unwrap().to_string();
+    let body = format!(r#"{{"direction": "{}"}}"#, dir_name);
+    send(&server, "POST", "/games/0/move", &body);
+
+    let restored = Server::new(Some(dir.clone())).unwrap();
+    assert_eq!(
+        send(&server, "GET", "/games/0", "").1,
+        send(&restored, "GET", "/games/0", "").1
+    );
+    assert_eq!(
+        send(&server, "POST", "/games/0/undo", "").1,
+        send(&restored, "POST", "/games/0/undo", "").1
+    );
+    assert_eq!(
+        send(&server, "POST", "/games/0/move", &body).1,
+        send(&restored, "POST", "/games/0/move", &body).1
+    );
+    assert_eq!(1, send(&restored, "POST", "/games", "").1["id"]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
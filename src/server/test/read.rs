@@ -0,0 +1,33 @@
+use super::*;
+
+/// Affirm that the request line, headers and body of a request are read.
+#[test]
+fn with_body() {
+    let raw = "POST /games/0/move?x=1 HTTP/1.1\r\n\
+               Host: localhost\r\n\
+               Content-Length: 19\r\n\
+               \r\n\
+               {\"direction\":\"up\"}\n";
+    let req = Request::read(&mut raw.as_bytes()).unwrap();
+    assert_eq!("POST", req.method);
+    assert_eq!("/games/0/move", req.path);
+    assert_eq!(
+        Some("localhost"),
+        req.headers.get("host").map(String::as_str)
+    );
+    assert_eq!("{\"direction\":\"up\"}\n", req.body);
+}
+
+/// Affirm that a request without a body is read.
+#[test]
+fn without_body() {
+    let req = Request::read(&mut "GET /games HTTP/1.1\r\n\r\n".as_bytes()).unwrap();
+    assert_eq!("GET", req.method);
+    assert_eq!("", req.body);
+}
+
+/// Affirm that a malformed request line is rejected.
+#[test]
+fn malformed() {
+    assert!(Request::read(&mut "\r\n\r\n".as_bytes()).is_err());
+}
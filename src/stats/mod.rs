@@ -0,0 +1,291 @@
+//! Lifetime statistics of every game played interactively, kept as `stats.json` in the data
+//! directory or at a path given by `--stats`. Each game is tallied from the events of its
+//! movements as it is played.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::board::{Board, Event, Rules};
+use crate::{Direction, Move};
+
+/// The representation of the statistics of a single game.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Game {
+    /// The rules the game was played by. Games recorded before the rules were kept were played by
+    /// the classic rules.
+    #[serde(default)]
+    pub rules: Rules,
+    /// The score at the end of the game.
+    pub score: usize,
+    /// The largest cell value at the end of the game.
+    pub max_value: usize,
+    /// The number of shifts made, including those later undone.
+    pub moves: usize,
+    /// The number of undos made.
+    pub undos: usize,
+    /// The number of merges made, including those later undone.
+    pub merges: usize,
+    /// The number of shifts made in each direction.
+    pub directions: BTreeMap<Direction, usize>,
+    /// The statistics of the movements made by each player of a cooperative game, keyed by name,
+    /// each with the outcome of the whole game; empty for a game played alone.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub players: BTreeMap<String, Game>,
+}
+
+/// The representation of every game played.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    /// The statistics of each game, in the order they were played.
+    pub games: Vec<Game>,
+}
+
+/// The representation of the statistics aggregated over every game.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Summary {
+    /// The number of games played.
+    pub games: usize,
+    /// The mean score over every game.
+    pub mean_score: f64,
+    /// The median score over every game.
+    pub median_score: f64,
+    /// The best score of any game.
+    pub best_score: usize,
+    /// The number of shifts made in the longest game.
+    pub longest_game: usize,
+    /// The fraction of games reaching each value which was the largest of some game, keyed by the
+    /// rules the games were played by (as text) and then by the value. Games played by other rules
+    /// are not counted, as their values are not comparable.
+    pub reached: BTreeMap<String, BTreeMap<usize, f64>>,
+    /// The fraction of shifts made in each direction.
+    pub directions: BTreeMap<Direction, f64>,
+    /// The mean number of merges made by a shift.
+    pub merges_per_move: f64,
+    /// The number of undos made over every game.
+    pub undos: usize,
+    /// The fraction of games in which an undo was made.
+    pub games_with_undos: f64,
+}
+
+/// The representation of the statistics exported as JSON.
+#[derive(Serialize)]
+struct Export<'a> {
+    summary: Summary,
+    games: &'a [Game],
+}
+
+impl Game {
+    /// Tallies a movement made in the game.
+    ///
+    /// # Arguments
+    ///
+    /// * `mov` - the movement that was made
+    /// * `events` - the events that followed the movement
+    pub fn observe(&mut self, mov: Move, events: &[Event]) {
+        match mov {
+            Move::Shift(dir) => {
+                self.moves += 1;
+                *self.directions.entry(dir).or_default() += 1;
+            }
+            Move::Undo => self.undos += 1,
+        }
+        self.merges += events
+            .iter()
+            .filter(|event| matches!(event, Event::Merge { .. }))
+            .count();
+    }
+
+    /// Records the outcome of the game from its final board.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - the final board of the game
+    pub fn finish(&mut self, board: &Board) {
+        self.rules = board.rules().clone();
+        self.score = board.score();
+        self.max_value = board.max_value();
+    }
+}
+
+/// Aggregates the statistics of every game.
+///
+/// # Arguments
+///
+/// * `games` - the statistics of each game
+pub fn summarize(games: &[Game]) -> Summary {
+    let n = games.len().max(1) as f64;
+    let mut scores = games.iter().map(|g| g.score).collect::<Vec<usize>>();
+    scores.sort_unstable();
+    let median_score = match scores.len() {
+        0 => 0.0,
+        len if len % 2 == 1 => scores[len / 2] as f64,
+        len => (scores[len / 2 - 1] + scores[len / 2]) as f64 / 2.0,
+    };
+
+    // every largest value of a game was reached by each game played by the same rules whose
+    // largest value is no smaller
+    let mut reached = BTreeMap::<String, BTreeMap<usize, f64>>::new();
+    for game in games {
+        let same = games
+            .iter()
+            .filter(|g| g.rules == game.rules)
+            .collect::<Vec<&Game>>();
+        let count = same
+            .iter()
+            .filter(|g| g.max_value >= game.max_value)
+            .count();
+        reached
+            .entry(game.rules.to_string())
+            .or_default()
+            .insert(game.max_value, count as f64 / same.len() as f64);
+    }
+
+    let moves = games.iter().map(|g| g.moves).sum::<usize>();
+    let mut directions = Direction::ALL
+        .into_iter()
+        .map(|dir| (dir, 0))
+        .collect::<BTreeMap<Direction, usize>>();
+    for game in games {
+        for (dir, count) in &game.directions {
+            *directions.entry(*dir).or_default() += count;
+        }
+    }
+
+    Summary {
+        games: games.len(),
+        mean_score: scores.iter().sum::<usize>() as f64 / n,
+        median_score,
+        best_score: scores.last().copied().unwrap_or(0),
+        longest_game: games.iter().map(|g| g.moves).max().unwrap_or(0),
+        reached,
+        directions: directions
+            .into_iter()
+            .map(|(dir, count)| (dir, count as f64 / moves.max(1) as f64))
+            .collect(),
+        merges_per_move: games.iter().map(|g| g.merges).sum::<usize>() as f64 / moves.max(1) as f64,
+        undos: games.iter().map(|g| g.undos).sum(),
+        games_with_undos: games.iter().filter(|g| g.undos > 0).count() as f64 / n,
+    }
+}
+
+/// Returns the statistics of every game as comma-separated values, with a header row. The rules
+/// are quoted, as they hold commas. The diagonal directions and the directions through the layers
+/// of a cube are only given columns if some game was shifted in one of them.
+///
+/// # Arguments
+///
+/// * `games` - the statistics of each game
+pub fn to_csv(games: &[Game]) -> String {
+    let used = |dirs: &[Direction]| {
+        games
+            .iter()
+            .any(|g| dirs.iter().any(|dir| g.directions.contains_key(dir)))
+    };
+    let mut directions = Direction::ALL.to_vec();
+    for extra in [&Direction::DIAGONALS[..], &Direction::DEPTH[..]] {
+        if used(extra) {
+            directions.extend(extra);
+        }
+    }
+
+    let mut csv = "game,rules,score,max_value,moves,undos,merges".to_string();
+    for dir in &directions {
+        csv += &format!(",{}", dir);
+    }
+    for (i, g) in games.iter().enumerate() {
+        csv += &format!(
+            "\n{},{},{},{},{},{},{}",
+            i + 1,
+            crate::tournament::quote(&g.rules.to_string()),
+            g.score,
+            g.max_value,
+            g.moves,
+            g.undos,
+            g.merges
+        );
+        for dir in &directions {
+            csv += &format!(",{}", g.directions.get(dir).unwrap_or(&0));
+        }
+    }
+    csv + "\n"
+}
+
+/// Returns the statistics of every game, along with their summary, as JSON.
+///
+/// # Arguments
+///
+/// * `games` - the statistics of each game
+pub fn to_json(games: &[Game]) -> String {
+    serde_json::to_string_pretty(&Export {
+        summary: summarize(games),
+        games,
+    })
+    .unwrap()
+}
+
+/// Returns the summary as human readable text.
+///
+/// # Arguments
+///
+/// * `summary` - the statistics aggregated over every game
+pub fn to_text(summary: &Summary) -> String {
+    let mut text = format!(
+        "games: {}\n\
+         mean score: {:.1}\n\
+         median score: {:.1}\n\
+         best score: {}\n\
+         longest game: {} moves\n\
+         merges per move: {:.2}\n\
+         undos: {} (in {:.1}% of games)\n",
+        summary.games,
+        summary.mean_score,
+        summary.median_score,
+        summary.best_score,
+        summary.longest_game,
+        summary.merges_per_move,
+        summary.undos,
+        summary.games_with_undos * 100.0
+    );
+
+    text += "\ndirections:";
+    for (dir, rate) in &summary.directions {
+        text += &format!("\n  {:>9}  {:>5.1}%", dir.to_string(), rate * 100.0);
+    }
+    for (rules, reached) in &summary.reached {
+        text += &format!("\n\nreached ({}):", rules);
+        for (value, rate) in reached {
+            text += &format!("\n  {:>9}  {:>5.1}%", value, rate * 100.0);
+        }
+    }
+    text
+}
+
+/// Runs the `stats` mode: prints the lifetime statistics, and exports them to the files given by
+/// `--csv` and `--json`.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let path = crate::data::path(&args, "stats", "stats.json")?;
+    let history = crate::data::load::<History>(&path)?;
+
+    if history.games.is_empty() {
+        println!("no games recorded in {}", path.display());
+    } else {
+        println!("{}", to_text(&summarize(&history.games)));
+    }
+
+    if let Some(path) = args.get::<String>("csv")? {
+        std::fs::write(&path, to_csv(&history.games)).map_err(|e| format!("{}: {}", path, e))?;
+    }
+    if let Some(path) = args.get::<String>("json")? {
+        std::fs::write(&path, to_json(&history.games)).map_err(|e| format!("{}: {}", path, e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,20 @@
+use super::*;
+
+mod observe;
+mod summarize;
+mod to_csv;
+
+/// Creates the statistics of a game played by the classic rules, with the specified score, largest
+/// cell and shifts made.
+fn game(score: usize, max_value: usize, directions: &[(Direction, usize)]) -> Game {
+    Game {
+        rules: Rules::default(),
+        score,
+        max_value,
+        moves: directions.iter().map(|(_, count)| count).sum(),
+        undos: 0,
+        merges: 0,
+        directions: directions.iter().copied().collect(),
+        players: BTreeMap::new(),
+    }
+}
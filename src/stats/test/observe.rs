@@ -0,0 +1,23 @@
+use super::*;
+
+/// Affirm that `Game::observe` tallies shifts, undos and merges from the engine's events, and that
+/// `Game::finish` records the rules of the game.
+#[test]
+fn tallies_events() {
+    let mut board: Board = "2,2,0,0/4,4,0,0/0,0,0,0/0,0,0,0".parse().unwrap();
+    let mut game = Game::default();
+
+    let events = board.movement(Move::Shift(Direction::Left)).unwrap();
+    game.observe(Move::Shift(Direction::Left), &events);
+    let events = board.movement(Move::Undo).unwrap();
+    game.observe(Move::Undo, &events);
+    game.finish(&board);
+
+    assert_eq!(1, game.moves);
+    assert_eq!(1, game.undos);
+    assert_eq!(2, game.merges);
+    assert_eq!(Some(&1), game.directions.get(&Direction::Left));
+    assert_eq!(0, game.score);
+    assert_eq!(4, game.max_value);
+    assert_eq!(board.rules(), &game.rules);
+}
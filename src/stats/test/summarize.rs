@@ -0,0 +1,74 @@
+use super::*;
+use crate::board::Variant;
+
+/// Affirm that the scores, cells reached and directions are aggregated over every game.
+#[test]
+fn aggregates() {
+    let mut games = vec![
+        game(100, 16, &[(Direction::Up, 3), (Direction::Left, 1)]),
+        game(300, 64, &[(Direction::Up, 4)]),
+        game(200, 32, &[(Direction::Down, 2)]),
+        game(400, 32, &[(Direction::Right, 10)]),
+    ];
+    games[0].merges = 10;
+    games[1].undos = 2;
+    let summary = summarize(&games);
+
+    assert_eq!(4, summary.games);
+    assert_eq!(250.0, summary.mean_score);
+    assert_eq!(250.0, summary.median_score);
+    assert_eq!(400, summary.best_score);
+    assert_eq!(10, summary.longest_game);
+    let reached = &summary.reached["4x4, win at 2048, 1 undo"];
+    assert_eq!(Some(&1.0), reached.get(&16));
+    assert_eq!(Some(&0.75), reached.get(&32));
+    assert_eq!(Some(&0.25), reached.get(&64));
+    assert_eq!(None, reached.get(&128));
+    assert_eq!(3, reached.len());
+    assert_eq!(1, summary.reached.len());
+    assert_eq!(Some(&0.35), summary.directions.get(&Direction::Up));
+    assert_eq!(
+        Some(&0.0),
+        summary.directions.get(&Direction::Left).map(|_| &0.0)
+    );
+    assert_eq!(0.5, summary.merges_per_move);
+    assert_eq!(2, summary.undos);
+    assert_eq!(0.25, summary.games_with_undos);
+}
+
+/// Affirm that the cells reached are counted separately for the games played by each set of rules.
+#[test]
+fn reached_by_rules() {
+    let mut games = vec![
+        game(100, 16, &[(Direction::Up, 3)]),
+        game(300, 64, &[(Direction::Up, 4)]),
+        game(200, 89, &[(Direction::Down, 2)]),
+    ];
+    games[2].rules = Rules {
+        win_value: 2584,
+        variant: Variant::Fibonacci,
+        ..Rules::default()
+    };
+    let summary = summarize(&games);
+
+    assert_eq!(3, summary.games);
+    assert_eq!(2, summary.reached.len());
+    let classic = &summary.reached["4x4, win at 2048, 1 undo"];
+    assert_eq!(Some(&1.0), classic.get(&16));
+    assert_eq!(Some(&0.5), classic.get(&64));
+    assert_eq!(None, classic.get(&89));
+    let fibonacci = &summary.reached["4x4, win at 2584, 1 undo, fibonacci"];
+    assert_eq!(Some(&1.0), fibonacci.get(&89));
+    assert_eq!(1, fibonacci.len());
+}
+
+/// Affirm that no games are summarized without dividing by zero.
+#[test]
+fn empty() {
+    let summary = summarize(&[]);
+    assert_eq!(0, summary.games);
+    assert_eq!(0.0, summary.mean_score);
+    assert_eq!(0.0, summary.median_score);
+    assert_eq!(0.0, summary.merges_per_move);
+    assert!(summary.reached.is_empty());
+}
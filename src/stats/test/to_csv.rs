@@ -0,0 +1,42 @@
+use super::*;
+
+/// Affirm that each game is written as a row following the header, with its rules quoted and a
+/// column per direction.
+#[test]
+fn rows() {
+    let csv = to_csv(&[game(1200, 128, &[(Direction::Left, 3), (Direction::Up, 2)])]);
+    let mut lines = csv.lines();
+    assert_eq!(
+        Some("game,rules,score,max_value,moves,undos,merges,up,down,left,right"),
+        lines.next()
+    );
+    assert_eq!(
+        Some("1,\"4x4, win at 2048, 1 undo\",1200,128,5,0,0,2,0,3,0"),
+        lines.next()
+    );
+    assert_eq!(None, lines.next());
+}
+
+/// Affirm that the directions through the layers of a cube are only given columns if some game
+/// was shifted in one of them.
+#[test]
+fn depth() {
+    let csv = to_csv(&[
+        game(8, 4, &[(Direction::Left, 1)]),
+        game(16, 8, &[(Direction::In, 2)]),
+    ]);
+    let mut lines = csv.lines();
+    assert_eq!(
+        Some("game,rules,score,max_value,moves,undos,merges,up,down,left,right,in,out"),
+        lines.next()
+    );
+    let rules = "\"4x4, win at 2048, 1 undo\"";
+    assert_eq!(
+        Some(format!("1,{},8,4,1,0,0,0,0,1,0,0,0", rules).as_str()),
+        lines.next()
+    );
+    assert_eq!(
+        Some(format!("2,{},16,8,2,0,0,0,0,0,0,2,0", rules).as_str()),
+        lines.next()
+    );
+}
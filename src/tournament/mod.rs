@@ -0,0 +1,302 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::board::Board;
+use crate::protocol::host::Bot;
+
+/// Defines the cell values for which the rate at which each bot reached them is reported.
+const TARGETS: [usize; 4] = [512, 1024, 2048, 4096];
+
+/// The representation of the outcome of a single game played by a bot.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct GameResult {
+    /// The position of the bot in the list of bots, which tells apart bots launched with the same
+    /// command.
+    pub index: usize,
+    /// The command the bot was launched with.
+    pub bot: String,
+    /// The seed the game was played with.
+    pub seed: u64,
+    /// The score at the end of the game.
+    pub score: usize,
+    /// The largest cell value at the end of the game.
+    pub max_value: usize,
+    /// The number of moves played.
+    pub moves: usize,
+    /// The mean time, in milliseconds, the bot took to reply with a move.
+    pub ms_per_move: f64,
+    /// The reason the game ended.
+    pub ending: String,
+}
+
+/// The representation of the aggregated results of every game played by a bot.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Summary {
+    /// The command the bot was launched with.
+    pub bot: String,
+    /// The number of games played.
+    pub games: usize,
+    /// The mean score over every game.
+    pub mean_score: f64,
+    /// The highest score of any game.
+    pub best_score: usize,
+    /// The mean number of moves played per game.
+    pub mean_moves: f64,
+    /// The mean time, in milliseconds, the bot took to reply with a move over every game.
+    pub ms_per_move: f64,
+    /// The fraction of games in which each of the `TARGETS` was reached.
+    pub win_rates: BTreeMap<usize, f64>,
+}
+
+/// The representation of the results of a tournament as written to JSON.
+#[derive(Serialize)]
+struct Results<'a> {
+    games: &'a [GameResult],
+    summary: &'a [Summary],
+}
+
+/// Plays a single seeded game with a newly launched bot. A bot which cannot be launched loses the
+/// game without a move, the reason being recorded as its ending.
+///
+/// # Arguments
+///
+/// * `index` - the position of the bot in the list of bots
+/// * `command` - the command the bot is launched with
+/// * `seed` - the seed of the game
+/// * `limit` - the time the bot has to reply with each move
+fn play(index: usize, command: &str, seed: u64, limit: Duration) -> GameResult {
+    let launched = crate::args::split_words(command).and_then(|words| {
+        let (program, args) = words.split_first().ok_or("empty bot command")?;
+        Bot::launch(program, args)
+    });
+    let mut bot = match launched {
+        Ok(bot) => bot,
+        Err(e) => {
+            return GameResult {
+                index,
+                bot: command.to_string(),
+                seed,
+                score: 0,
+                max_value: 0,
+                moves: 0,
+                ms_per_move: 0.0,
+                ending: format!("LaunchFailed({:?})", e),
+            }
+        }
+    };
+    let report = bot.referee(Board::with_seed(seed), limit);
+
+    let total = report.times.iter().sum::<Duration>();
+    GameResult {
+        index,
+        bot: command.to_string(),
+        seed,
+        score: report.board.score(),
+        max_value: report.board.max_value(),
+        moves: report.moves,
+        ms_per_move: match report.times.len() {
+            0 => 0.0,
+            n => total.as_secs_f64() * 1000.0 / n as f64,
+        },
+        ending: format!("{:?}", report.ending),
+    }
+}
+
+/// Plays `games` seeded games with each bot on up to `threads` games at once. Every bot is given
+/// the same seeds, being `seed`, `seed + 1`, and so on. A bot which cannot be launched loses each
+/// of its games, while the other bots play on.
+///
+/// # Arguments
+///
+/// * `bots` - the commands the bots are launched with
+/// * `games` - the number of games played by each bot
+/// * `seed` - the seed of the first game
+/// * `limit` - the time each bot has to reply with each move
+/// * `threads` - the greatest number of games played at once
+pub fn compete(
+    bots: &[String],
+    games: usize,
+    seed: u64,
+    limit: Duration,
+    threads: usize,
+) -> Vec<GameResult> {
+    let jobs = Mutex::new(bots.iter().enumerate().flat_map(|(index, bot)| {
+        (0..games as u64).map(move |i| (index, bot, seed.wrapping_add(i)))
+    }));
+    let results = Mutex::new(Vec::new());
+
+    std::thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| loop {
+                let job = jobs.lock().unwrap().next();
+                match job {
+                    Some((index, bot, seed)) => {
+                        let result = play(index, bot, seed, limit);
+                        results.lock().unwrap().push(result);
+                    }
+                    None => break,
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|r| (r.index, r.seed.wrapping_sub(seed)));
+    results
+}
+
+/// Aggregates the results of the games played by each bot, in the order the bots are listed.
+///
+/// # Arguments
+///
+/// * `bots` - the commands the bots were launched with
+/// * `results` - the results of every game
+pub fn summarize(bots: &[String], results: &[GameResult]) -> Vec<Summary> {
+    bots.iter()
+        .enumerate()
+        .map(|(index, bot)| {
+            let games = results
+                .iter()
+                .filter(|r| r.index == index)
+                .collect::<Vec<_>>();
+            let n = games.len().max(1) as f64;
+            let mean = |f: &dyn Fn(&GameResult) -> f64| games.iter().map(|r| f(r)).sum::<f64>() / n;
+
+            Summary {
+                bot: bot.clone(),
+                games: games.len(),
+                mean_score: mean(&|r| r.score as f64),
+                best_score: games.iter().map(|r| r.score).max().unwrap_or(0),
+                mean_moves: mean(&|r| r.moves as f64),
+                ms_per_move: mean(&|r| r.ms_per_move),
+                win_rates: TARGETS
+                    .into_iter()
+                    .map(|t| (t, mean(&|r| (r.max_value >= t) as u8 as f64)))
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+/// Returns text quoted as a field of comma-separated values, doubling any quotes within it.
+///
+/// # Arguments
+///
+/// * `text` - the text to quote
+pub fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\"\""))
+}
+
+/// Returns the results of every game as comma-separated values, with a header row. Each game
+/// gives the index of its bot, so bots launched with the same command can be told apart. The bot
+/// and the ending are quoted, as either may hold commas, quotes or line breaks.
+///
+/// # Arguments
+///
+/// * `results` - the results of every game
+pub fn to_csv(results: &[GameResult]) -> String {
+    let mut csv = "index,bot,seed,score,max_value,moves,ms_per_move,ending\n".to_string();
+    for r in results {
+        csv += &format!(
+            "{},{},{},{},{},{},{:.3},{}\n",
+            r.index,
+            quote(&r.bot),
+            r.seed,
+            r.score,
+            r.max_value,
+            r.moves,
+            r.ms_per_move,
+            quote(&r.ending)
+        );
+    }
+    csv
+}
+
+/// Returns the results of every game, along with the summaries, as JSON.
+///
+/// # Arguments
+///
+/// * `results` - the results of every game
+/// * `summary` - the summaries of each bot
+pub fn to_json(results: &[GameResult], summary: &[Summary]) -> String {
+    serde_json::to_string_pretty(&Results {
+        games: results,
+        summary,
+    })
+    .unwrap()
+}
+
+/// Returns the summaries as a table, with one row per bot.
+///
+/// # Arguments
+///
+/// * `summary` - the summaries of each bot
+pub fn to_table(summary: &[Summary]) -> String {
+    let width = summary
+        .iter()
+        .map(|s| s.bot.len())
+        .chain([3])
+        .max()
+        .unwrap();
+
+    let mut table = format!(
+        "{:width$}  {:>5}  {:>10}  {:>8}  {:>8}  {:>8}",
+        "bot", "games", "mean score", "best", "moves", "ms/move"
+    );
+    for target in TARGETS {
+        table += &format!("  {:>6}", target);
+    }
+    for s in summary {
+        table += &format!(
+            "\n{:width$}  {:>5}  {:>10.1}  {:>8}  {:>8.1}  {:>8.3}",
+            s.bot, s.games, s.mean_score, s.best_score, s.mean_moves, s.ms_per_move
+        );
+        for rate in s.win_rates.values() {
+            table += &format!("  {:>5.1}%", rate * 100.0);
+        }
+    }
+    table
+}
+
+/// Runs the `tournament` mode: plays seeded games with each bot and reports the results.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    let games = args.get::<usize>("games")?.unwrap_or(10);
+    let seed = args.get::<u64>("seed")?.unwrap_or(0);
+    let limit = Duration::from_millis(args.get::<u64>("movetime")?.unwrap_or(1000));
+    let threads = match args.get::<usize>("threads")? {
+        Some(threads) => threads,
+        None => std::thread::available_parallelism().map_or(1, usize::from),
+    };
+    let bots = args.positional();
+    if bots.is_empty() {
+        return Err(
+            "usage: tournament [--games N] [--seed S] [--movetime MS] [--threads T] \
+                    [--csv PATH] [--json PATH] <bot command>..."
+                .to_string(),
+        );
+    }
+
+    let results = compete(bots, games, seed, limit, threads);
+    let summary = summarize(bots, &results);
+
+    if let Some(path) = args.get::<String>("csv")? {
+        std::fs::write(&path, to_csv(&results)).map_err(|e| format!("{}: {}", path, e))?;
+    }
+    if let Some(path) = args.get::<String>("json")? {
+        std::fs::write(&path, to_json(&results, &summary))
+            .map_err(|e| format!("{}: {}", path, e))?;
+    }
+    println!("{}", to_table(&summary));
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
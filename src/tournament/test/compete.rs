@@ -0,0 +1,41 @@
+use super::*;
+
+/// Defines a bot which completes the handshake and then resigns every game.
+const RESIGNING_BOT: &str =
+    "sh -c 'echo p2048ok;while read l;do case $l in go*)echo bestmove none;;esac;done'";
+
+/// Affirm that every bot plays a game for each of the same seeds, reported in order.
+#[cfg(unix)]
+#[test]
+fn same_seeds() {
+    // the same command twice still makes two bots, told apart by their position
+    let bots = vec![RESIGNING_BOT.to_string(), RESIGNING_BOT.to_string()];
+    let results = compete(&bots, 3, u64::MAX, Duration::from_secs(5), 4);
+
+    let seeds = results
+        .iter()
+        .map(|r| (r.index, r.seed))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![(0, u64::MAX), (0, 0), (0, 1), (1, u64::MAX), (1, 0), (1, 1)],
+        seeds
+    );
+    assert!(results
+        .iter()
+        .all(|r| r.moves == 0 && r.ending == "Resigned"));
+}
+
+/// Affirm that a bot which cannot be launched loses each of its games, while the other bots play.
+#[cfg(unix)]
+#[test]
+fn launch_failed() {
+    let bots = vec!["./no-such-bot".to_string(), RESIGNING_BOT.to_string()];
+    let results = compete(&bots, 2, 0, Duration::from_secs(5), 2);
+    assert_eq!(4, results.len());
+    assert!(results[..2]
+        .iter()
+        .all(|r| r.index == 0 && r.moves == 0 && r.ending.starts_with("LaunchFailed(")));
+    assert!(results[2..]
+        .iter()
+        .all(|r| r.index == 1 && r.ending == "Resigned"));
+}
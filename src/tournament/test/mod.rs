@@ -0,0 +1,21 @@
+use super::*;
+use crate::protocol::host::Ending;
+
+mod compete;
+mod summarize;
+mod to_csv;
+
+/// Creates the result of a game played by the bot at the specified position, with the specified
+/// outcome.
+fn result(index: usize, bot: &str, seed: u64, score: usize, max_value: usize) -> GameResult {
+    GameResult {
+        index,
+        bot: bot.to_string(),
+        seed,
+        score,
+        max_value,
+        moves: score / 10,
+        ms_per_move: 0.5,
+        ending: "GameOver".to_string(),
+    }
+}
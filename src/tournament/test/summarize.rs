@@ -0,0 +1,47 @@
+use super::*;
+
+/// Affirm that the results of each bot are aggregated separately, in the order of the bots.
+#[test]
+fn per_bot() {
+    let bots = vec!["a".to_string(), "b".to_string()];
+    let results = vec![
+        result(1, "b", 0, 100, 64),
+        result(0, "a", 0, 2000, 512),
+        result(0, "a", 1, 4000, 1024),
+    ];
+    let summary = summarize(&bots, &results);
+
+    assert_eq!(2, summary.len());
+    assert_eq!("a", summary[0].bot);
+    assert_eq!(2, summary[0].games);
+    assert_eq!(3000.0, summary[0].mean_score);
+    assert_eq!(4000, summary[0].best_score);
+    assert_eq!(300.0, summary[0].mean_moves);
+    assert_eq!(Some(&1.0), summary[0].win_rates.get(&512));
+    assert_eq!(Some(&0.5), summary[0].win_rates.get(&1024));
+    assert_eq!(Some(&0.0), summary[0].win_rates.get(&2048));
+
+    assert_eq!("b", summary[1].bot);
+    assert_eq!(1, summary[1].games);
+    assert_eq!(Some(&0.0), summary[1].win_rates.get(&512));
+}
+
+/// Affirm that a bot without any results is summarized without dividing by zero.
+#[test]
+fn no_games() {
+    let summary = summarize(&["a".to_string()], &[]);
+    assert_eq!(0, summary[0].games);
+    assert_eq!(0.0, summary[0].mean_score);
+}
+
+/// Affirm that bots launched with the same command are summarized separately.
+#[test]
+fn same_command() {
+    let bots = vec!["a".to_string(), "a".to_string()];
+    let results = vec![result(0, "a", 0, 2000, 512), result(1, "a", 0, 100, 64)];
+    let summary = summarize(&bots, &results);
+    assert_eq!(1, summary[0].games);
+    assert_eq!(2000.0, summary[0].mean_score);
+    assert_eq!(1, summary[1].games);
+    assert_eq!(100.0, summary[1].mean_score);
+}
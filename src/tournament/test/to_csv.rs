@@ -0,0 +1,38 @@
+use super::*;
+
+/// Affirm that each game is written as a row following the header, led by the index of its bot,
+/// with the bot and ending quoted.
+#[test]
+fn rows() {
+    let csv = to_csv(&[
+        result(0, "./bot --fast", 7, 1200, 128),
+        result(1, "./bot --fast", 7, 800, 64),
+    ]);
+    let mut lines = csv.lines();
+    assert_eq!(
+        Some("index,bot,seed,score,max_value,moves,ms_per_move,ending"),
+        lines.next()
+    );
+    assert_eq!(
+        Some("0,\"./bot --fast\",7,1200,128,120,0.500,\"GameOver\""),
+        lines.next()
+    );
+    assert_eq!(
+        Some("1,\"./bot --fast\",7,800,64,80,0.500,\"GameOver\""),
+        lines.next()
+    );
+    assert_eq!(None, lines.next());
+}
+
+/// Affirm that an ending holding commas and quotes, as the line of a malformed reply may, is kept
+/// within its field.
+#[test]
+fn escaped() {
+    let mut game = result(0, "bot", 7, 1200, 128);
+    game.ending = format!("{:?}", Ending::Malformed("a, \"b\"".to_string()));
+    let csv = to_csv(&[game]);
+    assert_eq!(
+        Some(r#"0,"bot",7,1200,128,120,0.500,"Malformed(""a, \""b\"""")""#),
+        csv.lines().nth(1)
+    );
+}
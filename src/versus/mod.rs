@@ -0,0 +1,356 @@
+//! Local head-to-head games: two boards created from the same seed, played side by side from one
+//! keyboard, with the first player shifting by `w`, `a`, `s` and `d` and the second by the arrow
+//! keys (or `i`, `j`, `k` and `l`).
+
+use std::io::Write;
+
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_pcg::Pcg64;
+
+use crate::board::{Board, BoardCoord, Event, Rules, Status};
+use crate::cell::saturate;
+use crate::{Cell, Direction, Move};
+
+/// The representation of how the players take their moves.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Pace {
+    /// The players take turns, the first moving first. A player whose board can no longer be
+    /// shifted is skipped.
+    Turns,
+    /// Either player may move at any time.
+    Race,
+}
+
+/// Implementation of the `FromStr` trait for `Pace`.
+impl std::str::FromStr for Pace {
+    type Err = ();
+
+    /// Parses a pace from its lowercase name, `turns` or `race`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "turns" => Ok(Pace::Turns),
+            "race" => Ok(Pace::Race),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The representation of how the winner is decided, unless a player reaches the winning value of
+/// the rules first, which wins outright.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Victory {
+    /// Both players play until their boards can no longer be shifted, and the higher score wins.
+    Score,
+    /// The first player whose board can no longer be shifted loses.
+    Survival,
+}
+
+/// Implementation of the `FromStr` trait for `Victory`.
+impl std::str::FromStr for Victory {
+    type Err = ();
+
+    /// Parses a victory condition from its lowercase name, `score` or `survival`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "score" => Ok(Victory::Score),
+            "survival" => Ok(Victory::Survival),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The representation of the state of a head-to-head game.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    /// The game goes on.
+    Playing,
+    /// The player (counting from zero) has won.
+    Won(usize),
+    /// Both boards can no longer be shifted, and their scores are equal.
+    Drawn,
+}
+
+/// The representation of a head-to-head game.
+pub struct Versus {
+    /// The board of each player.
+    boards: [Board; 2],
+    /// How the players take their moves.
+    pace: Pace,
+    /// How the winner is decided.
+    victory: Victory,
+    /// The value a merge must form to send a garbage cell onto the opponent's board, if any.
+    garbage: Option<usize>,
+    /// The player whose turn it is, when taking turns.
+    turn: usize,
+    /// The source of randomness for the coordinates of garbage cells.
+    rng: Pcg64,
+}
+
+impl Versus {
+    /// Starts a head-to-head game on two boards created from the same seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - the rules both boards are played by
+    /// * `seed` - the seed of both boards
+    /// * `pace` - how the players take their moves
+    /// * `victory` - how the winner is decided
+    /// * `garbage` - the value a merge must form to send a garbage cell onto the opponent's
+    ///   board, if any
+    pub fn new(
+        rules: Rules,
+        seed: u64,
+        pace: Pace,
+        victory: Victory,
+        garbage: Option<usize>,
+    ) -> Self {
+        Self {
+            boards: [
+                Board::with_rules(rules.clone(), seed),
+                Board::with_rules(rules, seed),
+            ],
+            pace,
+            victory,
+            garbage,
+            turn: 0,
+            rng: Pcg64::seed_from_u64(seed),
+        }
+    }
+
+    /// Returns the board of a player.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - the player, counting from zero
+    pub fn board(&self, player: usize) -> &Board {
+        &self.boards[player]
+    }
+
+    /// Returns the player whose turn it is, or `None` if either player may move.
+    pub fn turn(&self) -> Option<usize> {
+        match self.pace {
+            Pace::Turns => Some(self.turn),
+            Pace::Race => None,
+        }
+    }
+
+    /// Returns the state of the game, as decided by the status of each board.
+    pub fn outcome(&self) -> Outcome {
+        let status = self.boards.each_ref().map(Board::status);
+        if let Some(player) = status.iter().position(|s| *s == Status::Won) {
+            return Outcome::Won(player);
+        }
+        match (self.victory, status.map(|s| s == Status::Lost)) {
+            (Victory::Survival, [true, false]) => Outcome::Won(1),
+            (Victory::Survival, [false, true]) => Outcome::Won(0),
+            (_, [true, true]) => {
+                let [first, second] = self.boards.each_ref().map(Board::score);
+                match first.cmp(&second) {
+                    std::cmp::Ordering::Greater => Outcome::Won(0),
+                    std::cmp::Ordering::Less => Outcome::Won(1),
+                    std::cmp::Ordering::Equal => Outcome::Drawn,
+                }
+            }
+            _ => Outcome::Playing,
+        }
+    }
+
+    /// Shifts the board of a player, then passes the turn to the opponent if they can still move.
+    /// A merge forming the garbage value (or more) sends a garbage cell, holding the smallest value
+    /// spawned by the rules, onto a random empty coordinate of the opponent's board. Fails if the
+    /// game has ended, it is not the player's turn or the board cannot be shifted in the direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - the player, counting from zero
+    /// * `dir` - the direction in which to shift
+    ///
+    /// # Returns
+    /// * the coordinate of the opponent's board at which a garbage cell landed, if any
+    pub fn play(&mut self, player: usize, dir: Direction) -> Result<Option<BoardCoord>, ()> {
+        if self.outcome() != Outcome::Playing || self.turn().is_some_and(|turn| turn != player) {
+            return Err(());
+        }
+        let events = self.boards[player].movement(Move::Shift(dir))?;
+
+        let opponent = 1 - player;
+        if !self.boards[opponent].legal_moves().is_empty() {
+            self.turn = opponent;
+        }
+
+        let sent = self.garbage.is_some_and(|garbage| {
+            events.iter().any(|event| match event {
+                Event::Merge { cell, .. } => saturate(cell.value()) >= garbage,
+                _ => false,
+            })
+        });
+        match sent {
+            true => Ok(self.send_garbage(opponent)),
+            false => Ok(None),
+        }
+    }
+
+    /// Places a garbage cell onto a random empty coordinate of a player's board, returning the
+    /// coordinate, or `None` if the board is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - the player receiving the garbage cell
+    fn send_garbage(&mut self, player: usize) -> Option<BoardCoord> {
+        let board = &mut self.boards[player];
+        let rule = board.rules().variant.rule();
+        let empty = board
+            .get_cells_by_emptiness(true)
+            .collect::<Vec<BoardCoord>>();
+        let pos = *empty.choose(&mut self.rng)?;
+        let cell = Cell::of(rule.spawns()[0], rule).unwrap();
+        board.place(pos, cell).ok()?;
+        Some(pos)
+    }
+}
+
+/// Parses the keys pressed on a line into the shifts they make: `w`, `a`, `s` and `d` shift the
+/// first player's board, and the arrow keys (as sent by a terminal) or `i`, `j`, `k` and `l`
+/// shift the second's. Fails on the first key which is not a shift, in either case.
+///
+/// # Arguments
+///
+/// * `line` - the line of keys pressed
+pub fn parse_keys(line: &str) -> Result<Vec<(usize, Direction)>, char> {
+    let mut shifts = Vec::new();
+    let mut chars = line.trim().chars();
+    while let Some(c) = chars.next() {
+        let shift = match c.to_ascii_lowercase() {
+            'w' => (0, Direction::Up),
+            'a' => (0, Direction::Left),
+            's' => (0, Direction::Down),
+            'd' => (0, Direction::Right),
+            'i' => (1, Direction::Up),
+            'j' => (1, Direction::Left),
+            'k' => (1, Direction::Down),
+            'l' => (1, Direction::Right),
+            '\x1b' => match (chars.next(), chars.next()) {
+                (Some('['), Some('A')) => (1, Direction::Up),
+                (Some('['), Some('B')) => (1, Direction::Down),
+                (Some('['), Some('C')) => (1, Direction::Right),
+                (Some('['), Some('D')) => (1, Direction::Left),
+                _ => return Err(c),
+            },
+            c if c.is_whitespace() => continue,
+            _ => return Err(c),
+        };
+        shifts.push(shift);
+    }
+    Ok(shifts)
+}
+
+/// Returns two blocks of text side by side, each line of the first padded to the width of its
+/// longest line.
+///
+/// # Arguments
+///
+/// * `left` - the block on the left
+/// * `right` - the block on the right
+pub fn side_by_side(left: &str, right: &str) -> String {
+    let width = left.lines().map(|line| line.chars().count()).max();
+    let width = width.unwrap_or(0);
+    let (left, right) = (
+        left.lines().collect::<Vec<&str>>(),
+        right.lines().collect::<Vec<&str>>(),
+    );
+    (0..left.len().max(right.len()))
+        .map(|idx| {
+            let line = format!(
+                "{:width$}    {}",
+                left.get(idx).unwrap_or(&""),
+                right.get(idx).unwrap_or(&""),
+                width = width
+            );
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Runs the `versus` mode: plays a head-to-head game on two boards created from the same seed,
+/// reading the keys of both players from stdin, a line at a time. The players take turns, or
+/// race each other given `--pace race`. The higher score once both boards are stuck wins, or,
+/// given `--victory survival`, the player left standing. Given `--garbage VALUE`, a merge forming
+/// that value (or more) sends a garbage cell onto the opponent's board.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the mode
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = crate::args::Args::parse(args)?;
+    if !args.positional().is_empty() {
+        return Err(
+            "usage: versus [--pace turns|race] [--victory score|survival] [--garbage VALUE]"
+                .to_string(),
+        );
+    }
+    let seed = args.get::<u64>("seed")?.unwrap_or_else(rand::random);
+    let pace = args.get::<Pace>("pace")?.unwrap_or(Pace::Turns);
+    let victory = args.get::<Victory>("victory")?.unwrap_or(Victory::Score);
+    let garbage = args.get::<usize>("garbage")?;
+    let mut versus = Versus::new(args.rules()?, seed, pace, victory, garbage);
+
+    while versus.outcome() == Outcome::Playing {
+        let header = |player: usize| {
+            let mark = match versus.turn() == Some(player) {
+                true => " *",
+                false => "",
+            };
+            format!(
+                "player {}{}\nscore: {}\n\n{}",
+                player + 1,
+                mark,
+                versus.board(player).score(),
+                versus.board(player)
+            )
+        };
+        println!("{}\n", side_by_side(&header(0), &header(1)));
+
+        print!("move: ");
+        std::io::stdout().flush().unwrap();
+        let mut input = String::new();
+        if std::io::stdin().read_line(&mut input).unwrap() == 0 {
+            // stdin was closed, so the game is abandoned
+            return Ok(());
+        }
+
+        match parse_keys(&input) {
+            Ok(shifts) => {
+                for (player, dir) in shifts {
+                    match versus.play(player, dir) {
+                        Ok(Some(pos)) => println!(
+                            "player {} sent a garbage cell to ({}, {})",
+                            player + 1,
+                            pos.0,
+                            pos.1
+                        ),
+                        Ok(None) => {}
+                        Err(()) => println!("player {}: invalid move", player + 1),
+                    }
+                }
+            }
+            Err(c) => println!("invalid input `{}`", c.escape_default()),
+        }
+        println!();
+    }
+
+    println!(
+        "{}\n",
+        side_by_side(&versus.board(0).to_string(), &versus.board(1).to_string())
+    );
+    let scores = format!("{} to {}", versus.board(0).score(), versus.board(1).score());
+    match versus.outcome() {
+        Outcome::Won(player) => println!("player {} wins, {}", player + 1, scores),
+        _ => println!("draw, {}", scores),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,14 @@
+use super::*;
+
+mod outcome;
+mod parse_keys;
+mod play;
+mod side_by_side;
+
+/// Returns a head-to-head game whose boards both start from the position written.
+fn game(start: &str, pace: Pace, victory: Victory, garbage: Option<usize>) -> Versus {
+    let board = start.parse::<Board>().unwrap();
+    let mut versus = Versus::new(board.rules().clone(), 1, pace, victory, garbage);
+    versus.boards = [board.clone(), board];
+    versus
+}
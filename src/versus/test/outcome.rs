@@ -0,0 +1,32 @@
+use super::*;
+
+/// Affirm that, by score, the game goes on until both boards are stuck, and the higher score
+/// then wins.
+#[test]
+fn score() {
+    let mut versus = game("2,4/4,2", Pace::Race, Victory::Score, None);
+    versus.boards[1] = "2,2/4,8".parse::<Board>().unwrap();
+    assert_eq!(Outcome::Playing, versus.outcome());
+    versus.play(1, Direction::Left).unwrap();
+    while let Some(dir) = versus.board(1).legal_moves().first().copied() {
+        versus.play(1, dir).unwrap();
+    }
+    assert_eq!(Outcome::Won(1), versus.outcome());
+
+    let versus = game("2,4/4,2", Pace::Race, Victory::Score, None);
+    assert_eq!(Outcome::Drawn, versus.outcome());
+}
+
+/// Affirm that, by survival, the first player whose board is stuck loses, and that reaching the
+/// winning value wins outright.
+#[test]
+fn survival() {
+    let mut versus = game("2,2/4,8", Pace::Race, Victory::Survival, None);
+    versus.boards[0] = "2,4/4,2".parse::<Board>().unwrap();
+    assert_eq!(Outcome::Won(1), versus.outcome());
+    versus.play(1, Direction::Left).unwrap_err();
+
+    let mut versus = game("1024,1024/0,0", Pace::Turns, Victory::Survival, None);
+    versus.play(0, Direction::Left).unwrap();
+    assert_eq!(Outcome::Won(0), versus.outcome());
+}
@@ -0,0 +1,18 @@
+use super::*;
+
+/// Affirm that each player's keys shift their own board, including the arrow keys as sent by a
+/// terminal, and that anything else is rejected.
+#[test]
+fn players() {
+    assert_eq!(
+        Ok(vec![
+            (0, Direction::Up),
+            (1, Direction::Left),
+            (1, Direction::Down),
+            (0, Direction::Right),
+        ]),
+        parse_keys("w\x1b[Dk D\n")
+    );
+    assert_eq!(Err('x'), parse_keys("wx"));
+    assert_eq!(Err('\x1b'), parse_keys("\x1b[Z"));
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+/// Affirm that both boards start alike, and that taking turns rejects moves made out of turn.
+#[test]
+fn turns() {
+    let mut versus = Versus::new(Rules::default(), 7, Pace::Turns, Victory::Score, None);
+    assert_eq!(versus.board(0).notation(), versus.board(1).notation());
+    assert_eq!(Some(0), versus.turn());
+
+    let dir = versus.board(0).legal_moves()[0];
+    versus.play(1, dir).unwrap_err();
+    versus.play(0, dir).unwrap();
+    assert_eq!(Some(1), versus.turn());
+    versus.play(0, dir).unwrap_err();
+    versus.play(1, dir).unwrap();
+
+    // the same moves from the same seed spawn the same cells
+    assert_eq!(versus.board(0).notation(), versus.board(1).notation());
+}
+
+/// Affirm that racing lets either player move at any time, and that a player whose board is
+/// stuck is skipped when taking turns.
+#[test]
+fn race() {
+    let mut versus = game("2,2,0/0,0,0/0,0,0", Pace::Race, Victory::Score, None);
+    assert_eq!(None, versus.turn());
+    versus.play(1, Direction::Left).unwrap();
+    versus.play(1, Direction::Right).unwrap();
+    versus.play(0, Direction::Left).unwrap();
+
+    let mut versus = game("2,2,0/0,0,0/0,0,0", Pace::Turns, Victory::Score, None);
+    versus.boards[1] = "2,4/4,2".parse::<Board>().unwrap();
+    versus.play(0, Direction::Left).unwrap();
+    assert_eq!(Some(0), versus.turn());
+}
+
+/// Affirm that a merge forming the garbage value sends a garbage cell onto the opponent's
+/// board, and that smaller merges send nothing.
+#[test]
+fn garbage() {
+    let mut versus = game("4,4,0/2,2,0/0,0,0", Pace::Race, Victory::Score, Some(8));
+    let empty = versus.board(1).count_empty();
+    assert_eq!(None, versus.play(0, Direction::Down).unwrap());
+    assert_eq!(empty, versus.board(1).count_empty());
+
+    let pos = versus.play(0, Direction::Left).unwrap().unwrap();
+    assert_eq!(empty - 1, versus.board(1).count_empty());
+    assert_eq!(
+        Some("2".to_string()),
+        versus.board(1).cells()[pos.0][pos.1]
+            .as_ref()
+            .map(Cell::to_string)
+    );
+}
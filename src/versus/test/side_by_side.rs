@@ -0,0 +1,12 @@
+use super::*;
+
+/// Affirm that the lines of the left block are padded to its widest line, whichever block is
+/// taller.
+#[test]
+fn padded() {
+    assert_eq!(
+        "ab      1\nabcd    2\n        3",
+        side_by_side("ab\nabcd", "1\n2\n3")
+    );
+    assert_eq!("a    1\nb", side_by_side("a\nb", "1"));
+}